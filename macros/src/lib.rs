@@ -0,0 +1,415 @@
+//! Procedural macros for the arrform crate
+//!
+//! The macros are re-exported by arrform, use them from there.
+
+use std::{iter::Peekable, str::Chars};
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    parse_quote, Data, DeriveInput, Error, Expr, Fields, GenericArgument, LitStr, PathArguments,
+    Result, Token, Type,
+};
+
+/// An argument given as `expr: type`
+struct Arg {
+    expr: Expr,
+    ty: Type,
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let expr = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Arg { expr, ty })
+    }
+}
+
+struct Input {
+    fmt: LitStr,
+    args: Punctuated<Arg, Token![,]>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let fmt = input.parse()?;
+        let args = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+        Ok(Input { fmt, args })
+    }
+}
+
+/// Argument types with a known worst case length
+#[derive(Clone, Copy)]
+enum Kind {
+    Int { bits: usize, signed: bool },
+    Float { f64: bool },
+    Bool,
+    Char,
+    Str(usize),
+}
+
+impl Kind {
+    fn from_type(ty: &Type) -> Result<Kind> {
+        let unsupported = || {
+            Error::new_spanned(
+                ty,
+                "unsupported type, use an integer, float, bool, char or str<N> with at most N bytes",
+            )
+        };
+        let path = match ty {
+            Type::Path(p) if p.qself.is_none() && p.path.segments.len() == 1 => {
+                &p.path.segments[0]
+            }
+            _ => return Err(unsupported()),
+        };
+        let kind = match path.ident.to_string().as_str() {
+            "u8" => Kind::Int { bits: 8, signed: false },
+            "u16" => Kind::Int { bits: 16, signed: false },
+            "u32" => Kind::Int { bits: 32, signed: false },
+            "u64" | "usize" => Kind::Int { bits: 64, signed: false },
+            "u128" => Kind::Int { bits: 128, signed: false },
+            "i8" => Kind::Int { bits: 8, signed: true },
+            "i16" => Kind::Int { bits: 16, signed: true },
+            "i32" => Kind::Int { bits: 32, signed: true },
+            "i64" | "isize" => Kind::Int { bits: 64, signed: true },
+            "i128" => Kind::Int { bits: 128, signed: true },
+            "f32" => Kind::Float { f64: false },
+            "f64" => Kind::Float { f64: true },
+            "bool" => Kind::Bool,
+            "char" => Kind::Char,
+            "str" => {
+                let len = match &path.arguments {
+                    PathArguments::AngleBracketed(a) if a.args.len() == 1 => match &a.args[0] {
+                        GenericArgument::Const(Expr::Lit(lit)) => match &lit.lit {
+                            syn::Lit::Int(n) => n.base10_parse()?,
+                            _ => return Err(unsupported()),
+                        },
+                        _ => return Err(unsupported()),
+                    },
+                    _ => return Err(unsupported()),
+                };
+                return Ok(Kind::Str(len));
+            }
+            _ => return Err(unsupported()),
+        };
+        if path.arguments.is_empty() {
+            Ok(kind)
+        } else {
+            Err(unsupported())
+        }
+    }
+
+    /// Maximum length in bytes for a placeholder with the given spec
+    fn max_len(self, spec: &Spec) -> usize {
+        let len = match self {
+            Kind::Int { bits, signed } => {
+                let digits = match spec.radix {
+                    2 => bits,
+                    8 => bits.div_ceil(3),
+                    16 => bits / 4,
+                    // Decimal digits of the largest value
+                    _ => match bits {
+                        8 => 3,
+                        16 => 5,
+                        32 => 10,
+                        64 => 20,
+                        _ => 39,
+                    },
+                };
+                let prefix = if spec.alternate && spec.radix != 10 { 2 } else { 0 };
+                let sign = if signed || spec.plus { 1 } else { 0 };
+                digits + prefix + sign
+            }
+            Kind::Float { f64 } => {
+                // Integer digits of the largest value, or decimals of the shortest
+                // representation of the smallest value
+                let (int_digits, shortest_decimals) = if f64 { (309, 340) } else { (39, 53) };
+                match spec.precision {
+                    Some(p) => 1 + int_digits + 1 + p,
+                    None => 1 + int_digits.max(2 + shortest_decimals),
+                }
+            }
+            Kind::Bool => 5,
+            // Quotes and an escape like `\u{10ffff}`
+            Kind::Char if spec.debug => 12,
+            Kind::Char => 4,
+            // Quotes and escapes of up to 6 bytes per byte, like `\u{1f}`
+            Kind::Str(n) if spec.debug => 2 + 6 * n,
+            Kind::Str(n) => match spec.precision {
+                // The precision counts characters of up to 4 bytes
+                Some(p) => n.min(4 * p),
+                None => n,
+            },
+        };
+        // The fill character may take up to 4 bytes
+        len.max(spec.width * spec.fill_len)
+    }
+}
+
+#[derive(Default)]
+struct Spec {
+    fill_len: usize,
+    plus: bool,
+    alternate: bool,
+    width: usize,
+    precision: Option<usize>,
+    radix: u32,
+    debug: bool,
+}
+
+fn parse_spec(spec: &str, span: Span) -> Result<Spec> {
+    let unsupported = || Error::new(span, format!("unsupported format spec `{}`", spec));
+    let mut result = Spec { fill_len: 1, radix: 10, ..Spec::default() };
+    let mut chars = spec.chars().peekable();
+
+    // Fill and alignment
+    let mut ahead = spec.chars();
+    if let (Some(fill), Some('<' | '^' | '>')) = (ahead.next(), ahead.next()) {
+        result.fill_len = fill.len_utf8();
+        chars.next();
+        chars.next();
+    } else if matches!(chars.peek(), Some('<' | '^' | '>')) {
+        chars.next();
+    }
+    if chars.peek() == Some(&'+') {
+        result.plus = true;
+        chars.next();
+    } else if chars.peek() == Some(&'-') {
+        chars.next();
+    }
+    if chars.peek() == Some(&'#') {
+        result.alternate = true;
+        chars.next();
+    }
+    result.width = number(&mut chars).unwrap_or(0);
+    if chars.peek() == Some(&'.') {
+        chars.next();
+        result.precision = Some(number(&mut chars).ok_or_else(unsupported)?);
+    }
+    result.radix = match chars.next() {
+        None => 10,
+        Some('?') => {
+            result.debug = true;
+            10
+        }
+        Some('x' | 'X') => 16,
+        Some('o') => 8,
+        Some('b') => 2,
+        Some(_) => return Err(unsupported()),
+    };
+    if chars.next().is_some() {
+        return Err(unsupported());
+    }
+    Ok(result)
+}
+
+fn number(chars: &mut Peekable<Chars>) -> Option<usize> {
+    let mut n = None;
+    while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+        n = Some(n.unwrap_or(0) * 10 + d as usize);
+        chars.next();
+    }
+    n
+}
+
+/// Placeholders of the format string as argument index and spec
+fn parse_format(fmt: &str, span: Span) -> Result<(usize, Vec<(usize, Spec)>)> {
+    let mut literal = 0;
+    let mut placeholders = Vec::new();
+    let mut next_arg = 0;
+    let mut rest = fmt;
+    while let Some(i) = rest.find(['{', '}']) {
+        literal += i;
+        let brace = rest.as_bytes()[i];
+        if rest[i + 1..].starts_with(brace as char) {
+            literal += 1;
+            rest = &rest[i + 2..];
+            continue;
+        }
+        if brace == b'}' {
+            return Err(Error::new(span, "unmatched `}` in format string"));
+        }
+        let end = rest[i..].find('}').ok_or_else(|| Error::new(span, "unmatched `{`"))? + i;
+        let inner = &rest[i + 1..end];
+        let (position, spec) = inner.split_once(':').unwrap_or((inner, ""));
+        let index = if position.is_empty() {
+            next_arg += 1;
+            next_arg - 1
+        } else {
+            position.parse().map_err(|_| {
+                Error::new(span, "only positional arguments are supported, like `{}` or `{0}`")
+            })?
+        };
+        placeholders.push((index, parse_spec(spec, span)?));
+        rest = &rest[end + 1..];
+    }
+    Ok((literal + rest.len(), placeholders))
+}
+
+/// Format into a buffer, which is computed at compile time to be large enough
+///
+/// Each argument is given together with its type, like `temp: f32`. The size of the buffer is
+/// the length of the literal text plus the worst case length of each placeholder.
+#[proc_macro]
+pub fn arrform_auto(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: Input) -> Result<proc_macro2::TokenStream> {
+    let span = input.fmt.span();
+    let (literal, placeholders) = parse_format(&input.fmt.value(), span)?;
+    let kinds = input.args.iter().map(|arg| Kind::from_type(&arg.ty)).collect::<Result<Vec<_>>>()?;
+
+    let mut size = literal;
+    for (index, spec) in &placeholders {
+        let kind = kinds.get(*index).ok_or_else(|| {
+            Error::new(span, format!("missing argument {} for format string", index))
+        })?;
+        size += kind.max_len(spec);
+    }
+
+    let mut bindings = Vec::new();
+    let mut names = Vec::new();
+    for (i, (arg, kind)) in input.args.iter().zip(&kinds).enumerate() {
+        let name = format_ident!("__arg{}", i);
+        let expr = &arg.expr;
+        bindings.push(match kind {
+            Kind::Str(n) => quote! {
+                // Clip strings to their declared maximum length
+                let #name: &str = {
+                    let s: &str = #expr;
+                    let mut end = s.len().min(#n);
+                    while !s.is_char_boundary(end) {
+                        end -= 1;
+                    }
+                    &s[..end]
+                };
+            },
+            _ => {
+                let ty = &arg.ty;
+                quote! { let #name: #ty = #expr; }
+            }
+        });
+        names.push(name);
+    }
+
+    let fmt = &input.fmt;
+    Ok(quote! {{
+        #(#bindings)*
+        let mut af = ::arrform::ArrForm::<#size>::new();
+        af.format_lossy(format_args!(#fmt #(, #names)*));
+        af
+    }})
+}
+
+/// Derive `ArrDisplay`, which writes a value like `Name { field: value }`
+///
+/// Structs of all kinds and enums are supported. Every field must implement `ArrDisplay`.
+#[proc_macro_derive(ArrDisplay)]
+pub fn derive_arr_display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_display(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Bind the fields of a struct or variant and write them together with their names
+fn display_fields(
+    name: &str,
+    fields: &Fields,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let bindings = (0..fields.len()).map(|i| format_ident!("__f{}", i)).collect::<Vec<_>>();
+    let (pattern, open, close) = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|f| f.ident.as_ref().unwrap());
+            (quote! { { #(#idents: #bindings),* } }, " { ", " }")
+        }
+        Fields::Unnamed(_) => (quote! { ( #(#bindings),* ) }, "(", ")"),
+        Fields::Unit => (quote! {}, "", ""),
+    };
+    if fields.is_empty() {
+        return (pattern, quote! { af.write_bytes(#name.as_bytes())?; });
+    }
+    let writes = fields.iter().zip(&bindings).enumerate().map(|(i, (field, binding))| {
+        let sep = if i == 0 { "" } else { ", " };
+        let label = match &field.ident {
+            Some(ident) => format!("{}{}: ", sep, ident),
+            None => sep.to_string(),
+        };
+        quote! {
+            af.write_bytes(#label.as_bytes())?;
+            ::arrform::ArrDisplay::write_to(#binding, af)?;
+        }
+    });
+    let head = format!("{}{}", name, open);
+    (
+        pattern,
+        quote! {
+            af.write_bytes(#head.as_bytes())?;
+            #(#writes)*
+            af.write_bytes(#close.as_bytes())?;
+        },
+    )
+}
+
+fn expand_display(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, writes) = display_fields(&name, &data.fields);
+            quote! {
+                let #ident #pattern = self;
+                #writes
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let var = &variant.ident;
+                let (pattern, writes) = display_fields(&var.to_string(), &variant.fields);
+                quote! { #ident::#var #pattern => { #writes } }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(Span::call_site(), "ArrDisplay cannot be derived for unions"))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::arrform::ArrDisplay));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::arrform::ArrDisplay for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn write_to<__S: ::arrform::Storage, __P: ::arrform::OverflowPolicy>(
+                &self,
+                af: &mut ::arrform::FormBuffer<__S, __P>,
+            ) -> ::core::fmt::Result {
+                #body
+                Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,42 @@
+//! Interoperability with heap allocated strings
+
+use alloc::string::String;
+
+use crate::{ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Copy the content into a heap allocated `String`
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let s: String = arrform!(16, "int {}", 42).into_string();
+    /// assert_eq!("int 42", s);
+    /// ```
+    pub fn into_string(self) -> String {
+        String::from(self.as_str())
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> From<FormBuffer<S, P>> for String {
+    fn from(af: FormBuffer<S, P>) -> Self {
+        af.into_string()
+    }
+}
+
+/// Creates new buffer from a heap allocated `String`
+/// ```
+/// use arrform::ArrForm;
+///
+/// let s = String::from("ready");
+/// assert_eq!("ready", ArrForm::<8>::try_from(&s).unwrap());
+/// assert!(ArrForm::<4>::try_from(&s).is_err());
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> TryFrom<&String>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    type Error = ArrFormError;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
@@ -0,0 +1,88 @@
+//! ANSI escape sequences for VT100 style terminals
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+const RESET: &[u8] = b"\x1b[0m";
+
+/// Foreground colors of [ArrForm::write_colored](crate::FormBuffer::write_colored)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append formatted text in the given color
+    ///
+    /// Room for the final reset sequence is kept free while formatting, so the terminal is
+    /// always set back to the default style, even if the text is clipped.
+    /// ```
+    /// use arrform::{ArrForm, Color};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_colored(Color::Red, format_args!("ERR {}", 42)).unwrap();
+    /// assert_eq!("\x1b[31mERR 42\x1b[0m", af.as_str());
+    ///
+    /// let mut af = ArrForm::<11>::new();
+    /// assert!(af.write_colored(Color::Red, format_args!("ERR {}", 42)).is_err());
+    /// assert_eq!("\x1b[31mER\x1b[0m", af.as_str());
+    /// ```
+    pub fn write_colored(&mut self, color: Color, args: fmt::Arguments) -> fmt::Result {
+        self.set_color(color)?;
+        let limit = self.limit;
+        self.limit = limit.saturating_sub(RESET.len()).max(self.used);
+        let result = self.append(args);
+        self.limit = limit;
+        self.reset_style().and(result)
+    }
+
+    /// Append the sequence, which switches the foreground color
+    pub fn set_color(&mut self, color: Color) -> fmt::Result {
+        self.write_escape(format_args!("\x1b[{}m", 30 + color as u8))
+    }
+
+    /// Append the sequence, which resets colors and other attributes
+    pub fn reset_style(&mut self) -> fmt::Result {
+        self.write_escape(format_args!("\x1b[0m"))
+    }
+
+    /// Append the sequence, which moves the cursor to `row` and `col`, counted from 1
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.clear_screen().unwrap();
+    /// af.move_to(2, 10).unwrap();
+    /// af.push_str("ready").unwrap();
+    /// assert_eq!("\x1b[2J\x1b[2;10Hready", af.as_str());
+    /// ```
+    pub fn move_to(&mut self, row: u16, col: u16) -> fmt::Result {
+        self.write_escape(format_args!("\x1b[{};{}H", row, col))
+    }
+
+    /// Append the sequence, which clears the screen
+    pub fn clear_screen(&mut self) -> fmt::Result {
+        self.write_escape(format_args!("\x1b[2J"))
+    }
+
+    /// Append the sequence, which clears the line of the cursor
+    pub fn clear_line(&mut self) -> fmt::Result {
+        self.write_escape(format_args!("\x1b[2K"))
+    }
+
+    /// Escape sequences are written completely or not at all
+    fn write_escape(&mut self, args: fmt::Arguments) -> fmt::Result {
+        let mut seq = crate::ArrForm::<16>::new();
+        seq.format(args)?;
+        let fit = if seq.len() <= self.limit - self.used { seq.len() } else { 0 };
+        self.write_prefix(seq.as_bytes(), fit)
+    }
+}
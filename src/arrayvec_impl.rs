@@ -0,0 +1,102 @@
+//! Interoperability with the `arrayvec` crate
+
+use core::str::from_utf8;
+
+use arrayvec::{ArrayString, ArrayVec};
+
+use crate::{ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Copy the content into an `arrayvec::ArrayString` with a capacity of `N` bytes
+    ///
+    /// An error is returned if the content does not fit into the string.
+    /// ```
+    /// use arrayvec::ArrayString;
+    /// use arrform::arrform;
+    ///
+    /// let s: ArrayString<8> = arrform!(16, "int {}", 42).to_array_string().unwrap();
+    /// assert_eq!("int 42", s.as_str());
+    /// assert!(arrform!(16, "int {}", 4711).to_array_string::<4>().is_err());
+    /// ```
+    pub fn to_array_string<const N: usize>(&self) -> Result<ArrayString<N>, ArrFormError> {
+        ArrayString::from(self.as_str()).map_err(|_| ArrFormError::Overflow {
+            capacity: N,
+            needed_at_least: self.used,
+        })
+    }
+}
+
+/// Creates new buffer from an `arrayvec::ArrayString` of the same capacity
+/// ```
+/// use arrayvec::ArrayString;
+/// use arrform::ArrForm;
+///
+/// let s = ArrayString::<8>::from("ready").unwrap();
+/// assert_eq!("ready", ArrForm::<8>::from(s));
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> From<ArrayString<BUF_SIZE>>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    fn from(s: ArrayString<BUF_SIZE>) -> Self {
+        // Cannot fail, the string is not longer than the buffer
+        Self::try_from(s.as_str()).unwrap_or_default()
+    }
+}
+
+/// Converts the buffer into an `arrayvec::ArrayString` of the same capacity
+/// ```
+/// use arrayvec::ArrayString;
+/// use arrform::arrform;
+///
+/// let s: ArrayString<16> = arrform!(16, "int {}", 42).into();
+/// assert_eq!("int 42", s.as_str());
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> From<FormBuffer<[u8; BUF_SIZE], P>>
+    for ArrayString<BUF_SIZE>
+{
+    fn from(af: FormBuffer<[u8; BUF_SIZE], P>) -> Self {
+        // Cannot fail, the content is not longer than the string
+        af.to_array_string().unwrap_or_default()
+    }
+}
+
+/// Converts the buffer into an `arrayvec::ArrayVec` of bytes with the same capacity
+///
+/// The content is copied as bytes, so binary frames are converted as well.
+/// ```
+/// use arrayvec::ArrayVec;
+/// use arrform::arrform;
+///
+/// let v: ArrayVec<u8, 16> = arrform!(16, "int {}", 42).into();
+/// assert_eq!(b"int 42", v.as_slice());
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> From<FormBuffer<[u8; BUF_SIZE], P>>
+    for ArrayVec<u8, BUF_SIZE>
+{
+    fn from(af: FormBuffer<[u8; BUF_SIZE], P>) -> Self {
+        // Cannot fail, the content is not longer than the vector
+        ArrayVec::try_from(af.as_bytes()).unwrap_or_default()
+    }
+}
+
+/// Creates new buffer from text given as `arrayvec::ArrayVec` of bytes
+///
+/// [ArrFormError::Fmt] is returned if the bytes are not valid UTF-8.
+/// ```
+/// use arrayvec::ArrayVec;
+/// use arrform::ArrForm;
+///
+/// let v = ArrayVec::<u8, 8>::try_from(&b"4711"[..]).unwrap();
+/// assert_eq!("4711", ArrForm::<4>::try_from(&v).unwrap());
+/// assert!(ArrForm::<3>::try_from(&v).is_err());
+/// ```
+impl<const BUF_SIZE: usize, const N: usize, P: OverflowPolicy> TryFrom<&ArrayVec<u8, N>>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    type Error = ArrFormError;
+
+    fn try_from(v: &ArrayVec<u8, N>) -> Result<Self, Self::Error> {
+        let s = from_utf8(v.as_slice()).map_err(|_| ArrFormError::Fmt)?;
+        Self::try_from(s)
+    }
+}
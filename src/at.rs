@@ -0,0 +1,89 @@
+//! AT commands for modems
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a builder, which appends an AT command to this buffer
+    pub fn at(&mut self) -> AtForm<'_, S, P> {
+        AtForm { buffer: self, args: 0, result: Ok(()) }
+    }
+}
+
+/// Builder for AT commands in a buffer
+///
+/// The arguments are separated by `=` and `,`. Strings are quoted, with `"`, `\` and `,`
+/// escaped by a backslash. The first error is kept and returned by [AtForm::finish], later
+/// calls do nothing.
+/// ```
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<64>::new();
+/// af.at().cmd("CWJAP").arg_str("home").arg_str("p\"w,1").finish().unwrap();
+/// assert_eq!("AT+CWJAP=\"home\",\"p\\\"w\\,1\"\r\n", af.as_str());
+///
+/// af.clear();
+/// af.at().cmd("CIPSEND").arg_int(0).arg_int(42).finish().unwrap();
+/// assert_eq!("AT+CIPSEND=0,42\r\n", af.as_str());
+/// ```
+pub struct AtForm<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+    args: usize,
+    result: fmt::Result,
+}
+
+impl<S: Storage, P: OverflowPolicy> AtForm<'_, S, P> {
+    /// Start the extended command `AT+name`
+    pub fn cmd(&mut self, name: &str) -> &mut Self {
+        self.args = 0;
+        self.write(|af| af.write_ascii(b"AT+").and_then(|_| af.write_text(name)))
+    }
+
+    /// Append a quoted and escaped string argument
+    pub fn arg_str(&mut self, s: &str) -> &mut Self {
+        self.arg(|af| {
+            af.write_ascii(b"\"")?;
+            let mut start = 0;
+            for (i, b) in s.bytes().enumerate() {
+                if matches!(b, b'"' | b'\\' | b',') {
+                    af.write_text(&s[start..i])?;
+                    af.write_ascii(b"\\")?;
+                    start = i;
+                }
+            }
+            af.write_text(&s[start..])?;
+            af.write_ascii(b"\"")
+        })
+    }
+
+    /// Append an integer argument
+    pub fn arg_int(&mut self, n: i32) -> &mut Self {
+        self.arg(|af| af.put_i32(n))
+    }
+
+    /// Append `"\r\n"` and get the result of all calls
+    pub fn finish(&mut self) -> fmt::Result {
+        self.write(|af| af.write_ascii(b"\r\n"));
+        self.result
+    }
+
+    fn arg<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut FormBuffer<S, P>) -> fmt::Result,
+    {
+        let separator = if self.args == 0 { b"=" } else { b"," };
+        self.args += 1;
+        self.write(|af| af.write_ascii(separator).and_then(|_| f(af)))
+    }
+
+    fn write<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut FormBuffer<S, P>) -> fmt::Result,
+    {
+        if self.result.is_ok() {
+            self.result = f(self.buffer);
+        }
+        self
+    }
+}
@@ -0,0 +1,65 @@
+//! Base64 encoding
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Alphabet of [ArrForm::write_base64](crate::FormBuffer::write_base64)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64 {
+    /// Standard alphabet with `+` and `/`, padded with `=`
+    Standard,
+    /// URL and file name safe alphabet with `-` and `_`, without padding
+    UrlSafe,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append `data` in base64 encoding
+    ///
+    /// The text is written in groups of four characters. On overflow, the groups which fit
+    /// are kept.
+    /// ```
+    /// use arrform::{ArrForm, Base64};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_base64(b"sensor", Base64::Standard).unwrap();
+    /// af.push_str(" ").unwrap();
+    /// af.write_base64(&[0xfb, 0xff], Base64::Standard).unwrap();
+    /// af.push_str(" ").unwrap();
+    /// af.write_base64(&[0xfb, 0xff], Base64::UrlSafe).unwrap();
+    /// assert_eq!("c2Vuc29y +/8= -_8", af.as_str());
+    ///
+    /// let mut af = ArrForm::<6>::new();
+    /// assert!(af.write_base64(b"sensor", Base64::Standard).is_err());
+    /// assert_eq!("c2Vu", af.as_str());
+    /// ```
+    pub fn write_base64(&mut self, data: &[u8], alphabet: Base64) -> fmt::Result {
+        let table = match alphabet {
+            Base64::Standard => STANDARD,
+            Base64::UrlSafe => URL_SAFE,
+        };
+        for chunk in data.chunks(3) {
+            let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, &b)| {
+                bits | (b as u32) << (16 - 8 * i)
+            });
+            let mut group = [b'='; 4];
+            for (i, c) in group.iter_mut().enumerate().take(chunk.len() + 1) {
+                *c = table.get((bits >> (18 - 6 * i) & 0x3f) as usize).copied().unwrap_or(b'=');
+            }
+            let len = match alphabet {
+                Base64::Standard => 4,
+                Base64::UrlSafe => chunk.len() + 1,
+            };
+            // Only whole groups are written
+            let fit = if len <= self.limit - self.used { len } else { 0 };
+            self.write_prefix(group.get(..len).unwrap_or_default(), fit)?;
+            if fit == 0 {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
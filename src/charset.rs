@@ -0,0 +1,88 @@
+//! Output in legacy 8-bit character sets of displays and terminals
+
+use crate::{ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+/// 8-bit character set for [ArrForm::encode_charset_into](crate::FormBuffer::encode_charset_into)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Charset {
+    /// Character ROM A00 of HD44780 compatible character LCDs
+    ///
+    /// ASCII is kept, except for `\` and `~`, whose codes show `¥` and `→`.
+    Hd44780A00,
+    /// Code page 437 of the IBM PC, used by many serial terminals and VGA text modes
+    Cp437,
+}
+
+/// Characters of HD44780 ROM A00 outside of ASCII, which have a Unicode counterpart
+const HD44780_A00: [(char, u8); 30] = [
+    ('¥', 0x5c), ('→', 0x7e), ('←', 0x7f), ('・', 0xa5), ('°', 0xdf), ('α', 0xe0),
+    ('ä', 0xe1), ('β', 0xe2), ('ß', 0xe2), ('ε', 0xe3), ('µ', 0xe4), ('μ', 0xe4),
+    ('σ', 0xe5), ('ρ', 0xe6), ('√', 0xe8), ('¢', 0xec), ('£', 0xed), ('ñ', 0xee),
+    ('ö', 0xef), ('θ', 0xf2), ('∞', 0xf3), ('Ω', 0xf4), ('ü', 0xf5), ('Σ', 0xf6),
+    ('π', 0xf7), ('千', 0xfa), ('万', 0xfb), ('円', 0xfc), ('÷', 0xfd), ('█', 0xff),
+];
+
+/// Characters of code page 437 from `0x80` to `0xff`
+const CP437_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ',
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+impl Charset {
+    /// Get the code of `c`, if the character set contains it
+    pub fn encode(self, c: char) -> Option<u8> {
+        match self {
+            Charset::Hd44780A00 => match c {
+                '\\' | '~' => None,
+                _ if c.is_ascii() => Some(c as u8),
+                _ => HD44780_A00.iter().find(|&&(u, _)| u == c).map(|&(_, code)| code),
+            },
+            Charset::Cp437 => match c {
+                _ if c.is_ascii() => Some(c as u8),
+                'μ' => Some(0xe6),
+                'β' => Some(0xe1),
+                _ => CP437_HIGH.iter().position(|&u| u == c).map(|i| 0x80 + i as u8),
+            },
+        }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Convert the content to an 8-bit character set in `out` and get the number of bytes written
+    ///
+    /// Each character becomes one byte, characters missing in the character set are replaced
+    /// by `substitute`. If `out` is too small, nothing is written and the needed size is
+    /// returned in the error.
+    /// ```
+    /// use arrform::{arrform, Charset};
+    ///
+    /// let line = arrform!(16, "{}°C {}µA", 21, 40);
+    /// let mut lcd = [0u8; 16];
+    /// let len = line.encode_charset_into(Charset::Hd44780A00, b'?', &mut lcd).unwrap();
+    /// assert_eq!(b"21\xdfC 40\xe4A", &lcd[..len]);
+    ///
+    /// let len = arrform!(16, "Größe ±1").encode_charset_into(Charset::Cp437, b'?', &mut lcd);
+    /// assert_eq!(b"Gr\x94\xe1e \xf11", &lcd[..len.unwrap()]);
+    /// ```
+    pub fn encode_charset_into(
+        &self,
+        charset: Charset,
+        substitute: u8,
+        out: &mut [u8],
+    ) -> Result<usize, ArrFormError> {
+        let needed = self.as_str().chars().count();
+        if needed > out.len() {
+            return Err(ArrFormError::Overflow { capacity: out.len(), needed_at_least: needed });
+        }
+        for (c, byte) in self.as_str().chars().zip(out.iter_mut()) {
+            *byte = charset.encode(c).unwrap_or(substitute);
+        }
+        Ok(needed)
+    }
+}
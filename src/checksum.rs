@@ -0,0 +1,50 @@
+//! Checksums accumulated while formatting
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Writer, which appends to a buffer and passes the written bytes to a checksum function
+///
+/// Only the bytes which made it into the buffer are passed on, so no second pass over the
+/// buffer is needed to compute a CRC or other checksum.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::ArrForm;
+///
+/// fn crc16_xmodem(mut crc: u16, bytes: &[u8]) -> u16 {
+///     for &b in bytes {
+///         crc ^= (b as u16) << 8;
+///         for _ in 0..8 {
+///             crc = if crc & 0x8000 != 0 { crc << 1 ^ 0x1021 } else { crc << 1 };
+///         }
+///     }
+///     crc
+/// }
+///
+/// let mut af = ArrForm::<32>::new();
+/// let mut crc = 0;
+/// write!(af.checksummed(|b| crc = crc16_xmodem(crc, b)), "{}", 123456789).unwrap();
+/// assert_eq!(0x31c3, crc);
+/// assert_eq!(crc16_xmodem(0, af.as_bytes()), crc);
+/// ```
+pub struct Checksummed<'a, S: Storage, P: OverflowPolicy, F: FnMut(&[u8])> {
+    buffer: &'a mut FormBuffer<S, P>,
+    update: F,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a writer, which appends to this buffer and passes the new bytes to `update`
+    pub fn checksummed<F: FnMut(&[u8])>(&mut self, update: F) -> Checksummed<'_, S, P, F> {
+        Checksummed { buffer: self, update }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy, F: FnMut(&[u8])> fmt::Write for Checksummed<'_, S, P, F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = self.buffer.used;
+        let result = self.buffer.write_text(s);
+        (self.update)(self.buffer.as_bytes().get(start..).unwrap_or_default());
+        result
+    }
+}
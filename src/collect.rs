@@ -0,0 +1,109 @@
+//! Collecting iterators of characters and strings
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append the items of an iterator, stopping at the first one which does not fit
+    fn extend_with<T>(
+        &mut self,
+        iter: impl IntoIterator<Item = T>,
+        mut write: impl FnMut(&mut Self, T) -> fmt::Result,
+    ) {
+        let dropped = self.dropped;
+        for item in iter {
+            if write(self, item).is_err() || self.dropped > dropped {
+                break;
+            }
+        }
+    }
+}
+
+impl<const BUF_SIZE: usize, P: OverflowPolicy> FormBuffer<[u8; BUF_SIZE], P> {
+    /// Collect an iterator into a new buffer, an error is returned if the items do not fit
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let line = "AT+CSQ: 17, 99\r\n";
+    /// let digits = ArrForm::<8>::try_from_iter(line.chars().filter(char::is_ascii_digit));
+    /// assert_eq!("1799", digits.unwrap().as_str());
+    ///
+    /// assert!(ArrForm::<4>::try_from_iter(["temp", "=", "21"]).is_err());
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, fmt::Error>
+    where
+        I: IntoIterator,
+        Self: Extend<I::Item>,
+    {
+        let mut af = Self::new();
+        af.extend(iter);
+        if af.truncated() {
+            return Err(fmt::Error);
+        }
+        Ok(af)
+    }
+}
+
+/// Appends characters, stopping at the first one which does not fit
+///
+/// Use [FormBuffer::truncated] to check whether all characters have been appended.
+/// ```
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<8>::new();
+/// af.extend("ok: 12.5".chars().filter(|c| !c.is_whitespace()));
+/// assert_eq!("ok:12.5", af.as_str());
+///
+/// af.extend(['µ', 'A']);
+/// assert_eq!("ok:12.5", af.as_str());
+/// assert!(af.truncated());
+/// ```
+impl<S: Storage, P: OverflowPolicy> Extend<char> for FormBuffer<S, P> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.extend_with(iter, |af, c| af.write_text(c.encode_utf8(&mut [0; 4])));
+    }
+}
+
+/// Appends strings, the text is cut at the first one which does not fit
+/// ```
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<16>::new();
+/// af.extend("T=21.5;H=45".split(';').flat_map(|s| [s, "\r\n"]));
+/// assert_eq!("T=21.5\r\nH=45\r\n", af.as_str());
+/// ```
+impl<'a, S: Storage, P: OverflowPolicy> Extend<&'a str> for FormBuffer<S, P> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.extend_with(iter, |af, s| af.write_text(s));
+    }
+}
+
+/// Collects characters into a new buffer, the text is cut if it does not fit
+/// ```
+/// use arrform::ArrForm;
+///
+/// let af: ArrForm<4> = "hello".chars().rev().collect();
+/// assert_eq!("olle", af.as_str());
+/// assert!(af.truncated());
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> FromIterator<char>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut af = Self::new();
+        af.extend(iter);
+        af
+    }
+}
+
+/// Collects strings into a new buffer, the text is cut if it does not fit
+impl<'a, const BUF_SIZE: usize, P: OverflowPolicy> FromIterator<&'a str>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut af = Self::new();
+        af.extend(iter);
+        af
+    }
+}
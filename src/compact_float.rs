@@ -0,0 +1,233 @@
+//! Small float formatting without the `core::fmt` float machinery
+
+use core::fmt;
+
+use crate::{FastArg, FormBuffer, OverflowPolicy, Storage};
+
+const POW10: [u32; 10] =
+    [1, 10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000, 100_000_000, 1_000_000_000];
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a float with a fixed number of decimals
+    ///
+    /// This is a compact replacement for `{:.N}`, which avoids about 30 kB of program code. At
+    /// most 9 decimals are supported, an error is returned for more. The computation is done
+    /// with `f32`, so only about 7 significant digits are exact and a value exactly between two
+    /// results may round differently than `core::fmt`.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_f32(21.56, 1).unwrap();
+    /// assert_eq!("21.6", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_f32(-0.996, 2).unwrap();
+    /// assert_eq!("-1.00", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_f32(f32::NAN, 2).unwrap();
+    /// assert_eq!("NaN", af.as_str());
+    ///
+    /// af.clear();
+    /// assert!(af.write_f32(0.5, 10).is_err());
+    /// assert_eq!("", af.as_str());
+    /// ```
+    pub fn write_f32(&mut self, v: f32, decimals: u8) -> fmt::Result {
+        self.write_f32_sep(v, decimals, '.')
+    }
+
+    /// Append a float like [ArrForm::write_f32](crate::FormBuffer::write_f32) with another
+    /// decimal separator
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_f32_sep(21.56, 1, ',').unwrap();
+    /// assert_eq!("21,6", af.as_str());
+    /// ```
+    pub fn write_f32_sep(&mut self, v: f32, decimals: u8, separator: char) -> fmt::Result {
+        let decimals = decimals as usize;
+        let scale = POW10.get(decimals).copied().ok_or(fmt::Error)?;
+        if v.is_nan() {
+            return self.write_ascii(b"NaN");
+        }
+        if v.is_sign_negative() {
+            self.write_ascii(b"-")?;
+        }
+        let mut v = v.abs();
+        if v.is_infinite() {
+            return self.write_ascii(b"inf");
+        }
+
+        // Scale large values into the range of u32 and add the zeros afterwards
+        let mut zeros = 0;
+        while v >= 1e9 {
+            v /= 10.0;
+            zeros += 1;
+        }
+
+        let mut int = v as u32;
+        let mut frac = ((v - int as f32) * scale as f32 + 0.5) as u32;
+        if frac >= scale {
+            int += 1;
+            frac -= scale;
+        }
+
+        self.write_u32(int)?;
+        for _ in 0..zeros {
+            self.write_ascii(b"0")?;
+        }
+        self.write_fraction(frac, decimals, separator)
+    }
+
+    /// Append an IEEE 754 half-precision float given as raw bits with a fixed number of decimals
+    ///
+    /// The value is widened exactly to `f32` and written like 
+    /// [ArrForm::write_f32](crate::FormBuffer::write_f32), so half-precision sensor data needs 
+    /// neither the `half` crate nor `core::fmt`.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_f16(0x4d70, 2).unwrap();
+    /// assert_eq!("21.75", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_f16(0xbc00, 1).unwrap();
+    /// assert_eq!("-1.0", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_f16(0x0001, 8).unwrap();
+    /// assert_eq!("0.00000006", af.as_str());
+    /// ```
+    pub fn write_f16(&mut self, bits: u16, decimals: u8) -> fmt::Result {
+        self.write_f32(f16_to_f32(bits), decimals)
+    }
+
+    /// Append a float in scientific notation with `sig_digits` significant digits
+    ///
+    /// The output looks like `{:.N e}` with `N = sig_digits - 1`, but needs much less program
+    /// code. At most 9 significant digits are supported, of which about 7 are exact, an error is
+    /// returned for more.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_exp(0.0000123, 3).unwrap();
+    /// assert_eq!("1.23e-5", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_exp(-99960.0, 3).unwrap();
+    /// assert_eq!("-1.00e5", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_exp(0.0, 1).unwrap();
+    /// assert_eq!("0e0", af.as_str());
+    ///
+    /// af.clear();
+    /// assert!(af.write_exp(1.0, 10).is_err());
+    /// ```
+    pub fn write_exp(&mut self, v: f32, sig_digits: u8) -> fmt::Result {
+        self.write_exp_sep(v, sig_digits, '.')
+    }
+
+    /// Append a float like [ArrForm::write_exp](crate::FormBuffer::write_exp) with another
+    /// decimal separator
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_exp_sep(0.0000123, 3, ',').unwrap();
+    /// assert_eq!("1,23e-5", af.as_str());
+    /// ```
+    pub fn write_exp_sep(&mut self, v: f32, sig_digits: u8, separator: char) -> fmt::Result {
+        let decimals = sig_digits.max(1) as usize - 1;
+        // Ten times the scale must still fit into u32 for the rounding
+        let scale = POW10.get(decimals + 1).and(POW10.get(decimals)).copied().ok_or(fmt::Error)?;
+        if v.is_nan() {
+            return self.write_ascii(b"NaN");
+        }
+        if v.is_sign_negative() {
+            self.write_ascii(b"-")?;
+        }
+        let mut v = v.abs();
+        if v.is_infinite() {
+            return self.write_ascii(b"inf");
+        }
+
+        // Normalize to a single digit before the point
+        let mut exp = 0;
+        if v != 0.0 {
+            while v >= 10.0 {
+                v /= 10.0;
+                exp += 1;
+            }
+            while v < 1.0 {
+                v *= 10.0;
+                exp -= 1;
+            }
+        }
+
+        let mut n = (v * scale as f32 + 0.5) as u32;
+        // Rounding carried over to 10.0
+        if n >= 10 * scale {
+            n = scale;
+            exp += 1;
+        }
+
+        // The single digit before the point, found without dividing
+        let mut int = 0;
+        while n >= scale {
+            n -= scale;
+            int += 1;
+        }
+        self.write_u32(int)?;
+        self.write_fraction(n, decimals, separator)?;
+        self.write_ascii(b"e")?;
+        self.write_i32(exp)
+    }
+
+    fn write_fraction(&mut self, frac: u32, decimals: usize, separator: char) -> fmt::Result {
+        if decimals == 0 {
+            return Ok(());
+        }
+        self.write_decimal_separator(separator)?;
+        // Fraction with leading zeros
+        self.write_u32_digits(frac, false, decimals)
+    }
+}
+
+/// Widen a half-precision float, every value is exactly representable as `f32`
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exp = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x3ff) as u32;
+    let magnitude = match exp {
+        // Zero and subnormal numbers
+        0 => mantissa as f32 / (1u32 << 24) as f32,
+        // Infinity and NaN
+        0x1f if mantissa == 0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => f32::from_bits((exp + 127 - 15) << 23 | mantissa << 13),
+    };
+    f32::from_bits(magnitude.to_bits() | sign)
+}
+
+/// A float with a fixed number of decimals for [arrform_fast!](crate::arrform_fast!)
+///
+/// The value is appended with [ArrForm::write_f32](crate::FormBuffer::write_f32).
+/// ```
+/// use arrform::{arrform_fast, Float};
+///
+/// let af = arrform_fast!(32, "T=", Float(21.56, 1), 'C');
+/// assert_eq!("T=21.6C", af.as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Float(pub f32, pub u8);
+
+impl FastArg for Float {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_f32(self.0, self.1)
+    }
+}
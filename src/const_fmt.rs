@@ -0,0 +1,119 @@
+//! Formatting at compile time
+
+// Slices can only be indexed directly in const functions. The indices are checked explicitly,
+// an overflow panics on purpose, which turns into a compile error in a const context.
+#![allow(clippy::indexing_slicing, clippy::panic)]
+
+use crate::{utf8::{from_utf8_trusted, valid_prefix}, FormBuffer, OverflowPolicy};
+
+impl<const BUF_SIZE: usize, P: OverflowPolicy> FormBuffer<[u8; BUF_SIZE], P> {
+    /// Append a string, the function can be evaluated at compile time
+    ///
+    /// The `const_*` functions are chained to assemble text in a `static` or `const` without
+    /// any formatting code in the program. An overflow panics, which fails the build if
+    /// evaluated at compile time.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// const MAJOR: u32 = 1;
+    /// const MINOR: u32 = 12;
+    /// const BUILD: u32 = 0x2a4f;
+    ///
+    /// static BANNER: ArrForm<32> = ArrForm::new()
+    ///     .const_str("fw v")
+    ///     .const_u32(MAJOR)
+    ///     .const_str(".")
+    ///     .const_u32(MINOR)
+    ///     .const_str(" build ")
+    ///     .const_hex(BUILD, 8);
+    ///
+    /// assert_eq!("fw v1.12 build 00002A4F", BANNER.as_const_str());
+    /// ```
+    ///
+    /// Text too long for the buffer is rejected by the compiler:
+    /// ```compile_fail
+    /// use arrform::ArrForm;
+    ///
+    /// static BANNER: ArrForm<4> = ArrForm::new().const_str("fw v").const_u32(1);
+    /// ```
+    pub const fn const_str(self, s: &str) -> Self {
+        self.const_bytes(s.as_bytes())
+    }
+
+    /// Append an unsigned number in decimal notation at compile time
+    pub const fn const_u32(self, v: u32) -> Self {
+        self.const_decimal(v, false)
+    }
+
+    /// Append a signed number in decimal notation at compile time
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// const OFFSET: ArrForm<16> = ArrForm::new().const_str("offset ").const_i32(-40);
+    /// assert_eq!("offset -40", OFFSET.as_const_str());
+    /// ```
+    pub const fn const_i32(self, v: i32) -> Self {
+        self.const_decimal(v.unsigned_abs(), v < 0)
+    }
+
+    /// Append a number in upper case hexadecimal notation with at least `width` digits at
+    /// compile time
+    pub const fn const_hex(self, mut v: u32, width: usize) -> Self {
+        let mut digits = [b'0'; 8];
+        let mut start = digits.len();
+        while v > 0 || start == digits.len() {
+            start -= 1;
+            digits[start] = b"0123456789ABCDEF"[(v & 0xf) as usize];
+            v >>= 4;
+        }
+        let mut af = self;
+        let mut len = digits.len() - start;
+        while len < width {
+            af = af.const_bytes(b"0");
+            len += 1;
+        }
+        af.const_bytes(digits.split_at(start).1)
+    }
+
+    /// Get the content as string slice, the function can be evaluated at compile time
+    pub const fn as_const_str(&self) -> &str {
+        let filled = self.buffer.split_at(self.used).0;
+        if self.raw {
+            return valid_prefix(filled);
+        }
+        // Only complete strings and ASCII digits have been copied in const functions
+        from_utf8_trusted(filled)
+    }
+
+    const fn const_decimal(self, mut v: u32, negative: bool) -> Self {
+        // Sign and 10 digits of u32::MAX, filled from the end
+        let mut digits = [0u8; 11];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (v % 10) as u8;
+            v /= 10;
+            if v == 0 {
+                break;
+            }
+        }
+        if negative {
+            start -= 1;
+            digits[start] = b'-';
+        }
+        self.const_bytes(digits.split_at(start).1)
+    }
+
+    const fn const_bytes(mut self, bytes: &[u8]) -> Self {
+        if bytes.len() > BUF_SIZE - self.used {
+            panic!("Buffer overflow");
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buffer[self.used + i] = bytes[i];
+            i += 1;
+        }
+        self.used += bytes.len();
+        self
+    }
+}
@@ -0,0 +1,64 @@
+//! Measuring the length of formatted text without a buffer
+
+use core::fmt;
+
+/// Counts the bytes of formatted text instead of storing it
+///
+/// This allows a measuring pass, e.g. to choose between a short and a long layout, without a 
+/// scratch buffer.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::{arrform, CountingForm};
+///
+/// let mut cf = CountingForm::new();
+/// write!(cf, "temp {:.1}°C", 21.56).unwrap();
+/// assert_eq!(12, cf.len());
+///
+/// let af = if CountingForm::measure(format_args!("temp {:.1}°C", 21.56)) <= 8 {
+///     arrform!(8, "temp {:.1}°C", 21.56)
+/// } else {
+///     arrform!(8, "{:.1}°C", 21.56)
+/// };
+/// assert_eq!("21.6°C", af.as_str());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CountingForm {
+    len: usize,
+}
+
+impl CountingForm {
+    /// Creates a counter starting at zero
+    pub const fn new() -> Self {
+        CountingForm { len: 0 }
+    }
+
+    /// Get the number of bytes, which a buffer needs for the text formatted with `args`
+    pub fn measure(args: fmt::Arguments) -> usize {
+        let mut cf = Self::new();
+        // Counting never fails, only a formatter of an argument can return an error
+        let _ = fmt::write(&mut cf, args);
+        cf.len
+    }
+
+    /// Get the number of bytes counted so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was counted
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reset the counter to zero
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl fmt::Write for CountingForm {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.len += s.len();
+        Ok(())
+    }
+}
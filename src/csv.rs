@@ -0,0 +1,91 @@
+//! CSV rows
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a builder, which appends CSV rows with the given delimiter to this buffer
+    pub fn csv(&mut self, delimiter: char) -> CsvForm<'_, S, P> {
+        CsvForm { buffer: self, delimiter, first: true, result: Ok(()) }
+    }
+}
+
+/// Builder for CSV rows in a buffer
+///
+/// Delimiters are inserted between the fields of a row. Text fields are quoted if they contain
+/// the delimiter, quotes or line breaks. The first error is kept and returned by
+/// [CsvForm::finish], later calls do nothing.
+/// ```
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<64>::new();
+/// let mut csv = af.csv(';');
+/// csv.field("time").field("note").field("temp").finish_row();
+/// csv.field_fmt(format_args!("{}", 120))
+///     .field("door \"A\"; open")
+///     .field_fmt(format_args!("{:.1}", 21.56))
+///     .finish_row();
+/// csv.finish().unwrap();
+/// assert_eq!("time;note;temp\r\n120;\"door \"\"A\"\"; open\";21.6\r\n", af.as_str());
+/// ```
+pub struct CsvForm<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+    delimiter: char,
+    first: bool,
+    result: fmt::Result,
+}
+
+impl<S: Storage, P: OverflowPolicy> CsvForm<'_, S, P> {
+    /// Append a text field, quoted if necessary
+    pub fn field(&mut self, s: &str) -> &mut Self {
+        let quote = s.contains([self.delimiter, '"', '\r', '\n']);
+        self.field_with(|af| {
+            if !quote {
+                return af.write_text(s);
+            }
+            af.write_ascii(b"\"")?;
+            for (i, part) in s.split('"').enumerate() {
+                if i > 0 {
+                    af.write_ascii(b"\"\"")?;
+                }
+                af.write_text(part)?;
+            }
+            af.write_ascii(b"\"")
+        })
+    }
+
+    /// Append a formatted field unchanged, e.g. a number
+    pub fn field_fmt(&mut self, args: fmt::Arguments) -> &mut Self {
+        self.field_with(|af| af.append(args))
+    }
+
+    /// Append the line terminator `"\r\n"` and start a new row
+    pub fn finish_row(&mut self) -> &mut Self {
+        if self.result.is_ok() {
+            self.result = self.buffer.write_ascii(b"\r\n");
+        }
+        self.first = true;
+        self
+    }
+
+    /// Get the result of all calls
+    pub fn finish(&mut self) -> fmt::Result {
+        self.result
+    }
+
+    fn field_with<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut FormBuffer<S, P>) -> fmt::Result,
+    {
+        if self.result.is_ok() && !self.first {
+            let mut encoded = [0u8; 4];
+            self.result = self.buffer.write_text(self.delimiter.encode_utf8(&mut encoded));
+        }
+        if self.result.is_ok() {
+            self.result = f(self.buffer);
+        }
+        self.first = false;
+        self
+    }
+}
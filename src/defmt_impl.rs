@@ -0,0 +1,10 @@
+//! Support for the `defmt` logging framework
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Allows logging the buffer with `defmt`, e.g. `defmt::info!("{}", af)`
+impl<S: Storage, P: OverflowPolicy> defmt::Format for FormBuffer<S, P> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
@@ -0,0 +1,133 @@
+//! Compact display of values without `core::fmt`
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Writes a value directly into a buffer
+///
+/// This is a lightweight alternative to `core::fmt::Debug`. With the `derive` feature, it can
+/// be derived for structs and enums, which are then written like `Config { baud: 9600, on: true
+/// }`. Integers up to 32 bits, `bool`, `char` and strings are written without `core::fmt`.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::{ArrDisplay, ArrForm};
+///
+/// struct Baud(u32);
+///
+/// impl ArrDisplay for Baud {
+///     fn write_to<S, P>(&self, af: &mut arrform::FormBuffer<S, P>) -> core::fmt::Result
+///     where
+///         S: arrform::Storage,
+///         P: arrform::OverflowPolicy,
+///     {
+///         self.0.write_to(af)?;
+///         af.write_str(" Bd")
+///     }
+/// }
+///
+/// let mut af = ArrForm::<16>::new();
+/// af.write_display(&Baud(9600)).unwrap();
+/// assert_eq!("9600 Bd", af.as_str());
+/// ```
+pub trait ArrDisplay {
+    /// Append the value to the buffer
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result;
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a value implementing [ArrDisplay]
+    pub fn write_display<T: ArrDisplay + ?Sized>(&mut self, value: &T) -> fmt::Result {
+        value.write_to(self)
+    }
+}
+
+impl<T: ArrDisplay + ?Sized> ArrDisplay for &T {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        (**self).write_to(af)
+    }
+}
+
+impl ArrDisplay for str {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_ascii(b"\"")?;
+        af.write_text(self)?;
+        af.write_ascii(b"\"")
+    }
+}
+
+impl ArrDisplay for char {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_text(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl ArrDisplay for bool {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_ascii(if *self { b"true" } else { b"false" })
+    }
+}
+
+impl<T: ArrDisplay> ArrDisplay for Option<T> {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        match self {
+            Some(v) => {
+                af.write_ascii(b"Some(")?;
+                v.write_to(af)?;
+                af.write_ascii(b")")
+            }
+            None => af.write_ascii(b"None"),
+        }
+    }
+}
+
+impl<T: ArrDisplay> ArrDisplay for [T] {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_ascii(b"[")?;
+        for (i, v) in self.iter().enumerate() {
+            if i > 0 {
+                af.write_ascii(b", ")?;
+            }
+            v.write_to(af)?;
+        }
+        af.write_ascii(b"]")
+    }
+}
+
+impl<T: ArrDisplay, const N: usize> ArrDisplay for [T; N] {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        self.as_slice().write_to(af)
+    }
+}
+
+macro_rules! display_small_int {
+    ($method:ident, $wide:ty, $($t:ty),+) => {$(
+        impl ArrDisplay for $t {
+            fn write_to<S: Storage, P: OverflowPolicy>(
+                &self,
+                af: &mut FormBuffer<S, P>,
+            ) -> fmt::Result {
+                af.$method(*self as $wide)
+            }
+        }
+    )+};
+}
+
+display_small_int!(put_u32, u32, u8, u16, u32);
+display_small_int!(put_i32, i32, i8, i16, i32);
+
+// Wider numbers and floats are left to `core::fmt`
+macro_rules! display_fmt {
+    ($($t:ty),+) => {$(
+        impl ArrDisplay for $t {
+            fn write_to<S: Storage, P: OverflowPolicy>(
+                &self,
+                af: &mut FormBuffer<S, P>,
+            ) -> fmt::Result {
+                af.append(format_args!("{}", self))
+            }
+        }
+    )+};
+}
+
+display_fmt!(u64, u128, usize, i64, i128, isize, f32, f64);
@@ -0,0 +1,67 @@
+//! Two buffers for formatting while the previous text is being sent
+
+use crate::ArrForm;
+
+/// Holds a front and a back buffer of `N` bytes each
+///
+/// Text is formatted into the back buffer while the front buffer is read, e.g. by a DMA
+/// transfer. [DoubleBuffer::swap] exchanges both, once the transfer is done.
+/// ```
+/// use arrform::DoubleBuffer;
+///
+/// let mut buffers = DoubleBuffer::<16>::new();
+/// buffers.back().format(format_args!("tick {}", 1)).unwrap();
+/// let tx = buffers.swap();
+/// assert_eq!(b"tick 1", tx);
+///
+/// // Format the next message while the first one is sent
+/// buffers.back().format(format_args!("tick {}", 2)).unwrap();
+/// assert_eq!(b"tick 1", buffers.front());
+/// assert_eq!(b"tick 2", buffers.swap());
+/// ```
+pub struct DoubleBuffer<const N: usize> {
+    buffers: [ArrForm<N>; 2],
+    front: usize,
+}
+
+impl<const N: usize> DoubleBuffer<N> {
+    /// Creates two empty buffers
+    pub const fn new() -> Self {
+        DoubleBuffer { buffers: [ArrForm::new(), ArrForm::new()], front: 0 }
+    }
+
+    /// Get the back buffer to format into
+    pub fn back(&mut self) -> &mut ArrForm<N> {
+        let [first, second] = &mut self.buffers;
+        if self.front == 0 {
+            second
+        } else {
+            first
+        }
+    }
+
+    /// Get the content of the front buffer
+    pub fn front(&self) -> &[u8] {
+        let [first, second] = &self.buffers;
+        if self.front == 0 {
+            first.as_bytes()
+        } else {
+            second.as_bytes()
+        }
+    }
+
+    /// Exchange front and back buffer and get the content of the new front buffer
+    ///
+    /// The new back buffer is cleared, so the previous front buffer must no longer be in use.
+    pub fn swap(&mut self) -> &[u8] {
+        self.front = 1 - self.front;
+        self.back().clear();
+        self.front()
+    }
+}
+
+impl<const N: usize> Default for DoubleBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
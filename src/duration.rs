@@ -0,0 +1,99 @@
+//! Durations for uptime and countdown displays
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Layout of [ArrForm::write_duration](crate::FormBuffer::write_duration)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// Units after each part, like `1h 02m 03s`, leading zero parts are omitted
+    Units,
+    /// Clock display, like `1:02:03` or `02:03` below one hour
+    Clock,
+    /// Clock display with milliseconds, like `02:03.450`
+    ClockMillis,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a duration given in milliseconds
+    /// ```
+    /// use arrform::{ArrForm, DurationStyle};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_duration(3_723_000, DurationStyle::Units).unwrap();
+    /// assert_eq!("1h 02m 03s", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_duration(123_450, DurationStyle::ClockMillis).unwrap();
+    /// assert_eq!("02:03.450", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_duration(3_723_000, DurationStyle::Clock).unwrap();
+    /// assert_eq!("1:02:03", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_duration(5_000, DurationStyle::Units).unwrap();
+    /// assert_eq!("5s", af.as_str());
+    /// ```
+    pub fn write_duration(&mut self, ms: u64, style: DurationStyle) -> fmt::Result {
+        self.write_duration_sep(ms, style, '.')
+    }
+
+    /// Append a duration like [ArrForm::write_duration] with another separator before the
+    /// milliseconds of [DurationStyle::ClockMillis]
+    /// ```
+    /// use arrform::{ArrForm, DurationStyle};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_duration_sep(123_450, DurationStyle::ClockMillis, ',').unwrap();
+    /// assert_eq!("02:03,450", af.as_str());
+    /// ```
+    ///
+    /// [ArrForm::write_duration]: crate::FormBuffer::write_duration
+    pub fn write_duration_sep(
+        &mut self,
+        ms: u64,
+        style: DurationStyle,
+        separator: char,
+    ) -> fmt::Result {
+        let millis = (ms % 1000) as u32;
+        let seconds = (ms / 1000 % 60) as u32;
+        let minutes = (ms / 60_000 % 60) as u32;
+        // Saturates after about 490,000 years
+        let hours = u32::try_from(ms / 3_600_000).unwrap_or(u32::MAX);
+
+        match style {
+            DurationStyle::Units => {
+                if hours > 0 {
+                    self.put_u32(hours)?;
+                    self.write_ascii(b"h ")?;
+                    self.write_zero_padded(minutes, 2)?;
+                    self.write_ascii(b"m ")?;
+                    self.write_zero_padded(seconds, 2)?;
+                } else if minutes > 0 {
+                    self.put_u32(minutes)?;
+                    self.write_ascii(b"m ")?;
+                    self.write_zero_padded(seconds, 2)?;
+                } else {
+                    self.put_u32(seconds)?;
+                }
+                self.write_ascii(b"s")
+            }
+            DurationStyle::Clock | DurationStyle::ClockMillis => {
+                if hours > 0 {
+                    self.put_u32(hours)?;
+                    self.write_ascii(b":")?;
+                }
+                self.write_zero_padded(minutes, 2)?;
+                self.write_ascii(b":")?;
+                self.write_zero_padded(seconds, 2)?;
+                if style == DurationStyle::ClockMillis {
+                    self.write_decimal_separator(separator)?;
+                    self.write_zero_padded(millis, 3)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Support for the `embedded-io` traits
+
+use crate::{utf8::text_prefix, ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+impl embedded_io::Error for ArrFormError {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self {
+            ArrFormError::Overflow { .. } => embedded_io::ErrorKind::WriteZero,
+            ArrFormError::Fmt => embedded_io::ErrorKind::Other,
+        }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> embedded_io::ErrorType for FormBuffer<S, P> {
+    type Error = ArrFormError;
+}
+
+/// Allows the buffer to be the target of driver code written against `embedded-io`
+///
+/// Writes are partial if the buffer is nearly full, the bytes are accepted up to the last
+/// complete character. Once no further character fits, an [ArrFormError::Overflow] is
+/// returned. Bytes, which are not valid UTF-8, fail with [ArrFormError::Fmt].
+/// ```
+/// use embedded_io::Write;
+/// use arrform::{ArrForm, ArrFormError};
+///
+/// let mut af = ArrForm::<8>::new();
+/// af.write_all(b"HTTP/1.1").unwrap();
+/// assert_eq!("HTTP/1.1", af.as_str());
+/// assert!(af.write_all(b" 200").is_err());
+///
+/// let mut af = ArrForm::<3>::new();
+/// assert_eq!(Ok(2), af.write("ab°C".as_bytes()));
+/// assert!(af.write("°C".as_bytes()).is_err());
+/// assert_eq!(Err(ArrFormError::Fmt), af.write(&[0xff]));
+/// ```
+impl<S: Storage, P: OverflowPolicy> embedded_io::Write for FormBuffer<S, P> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let text = text_prefix(buf, self.remaining()).map_err(|_| ArrFormError::Fmt)?;
+        if text.is_empty() && !buf.is_empty() {
+            return Err(ArrFormError::Overflow {
+                capacity: self.capacity(),
+                needed_at_least: self.len() + buf.len(),
+            });
+        }
+        self.write_text(text).map_err(|_| ArrFormError::Fmt)?;
+        Ok(text.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+//! Detailed error information
+
+use core::fmt;
+
+/// Error of a formatting operation, distinguishing buffer overflow from formatter errors
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrFormError {
+    /// The text did not fit into the buffer of `capacity` bytes
+    ///
+    /// `needed_at_least` is a lower bound of the required buffer size. Formatting stops at 
+    /// the first overflow, so the complete text may be even longer.
+    Overflow { capacity: usize, needed_at_least: usize },
+    /// A formatter of one of the arguments returned an error
+    Fmt,
+}
+
+impl fmt::Display for ArrFormError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrFormError::Overflow { capacity, needed_at_least } => write!(
+                f, "buffer overflow, capacity {} bytes, needed at least {} bytes",
+                capacity, needed_at_least
+            ),
+            ArrFormError::Fmt => f.write_str("formatting error"),
+        }
+    }
+}
+
+impl core::error::Error for ArrFormError {}
+
+impl From<ArrFormError> for fmt::Error {
+    fn from(_: ArrFormError) -> Self {
+        fmt::Error
+    }
+}
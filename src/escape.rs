@@ -0,0 +1,59 @@
+//! Readable output of binary data
+
+use core::fmt;
+
+use crate::{radix::digit, utf8::from_utf8_trusted, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append bytes with all but printable ASCII characters escaped
+    ///
+    /// `\r`, `\n`, `\t` and `\\` get their usual escapes, other bytes are written as `\xNN`. The
+    /// buffer so always holds valid text, whatever the bytes are.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_escaped(b"OK\r\n\x00\xff\\").unwrap();
+    /// assert_eq!(r"OK\r\n\x00\xff\\", af.as_str());
+    /// ```
+    pub fn write_escaped(&mut self, bytes: &[u8]) -> fmt::Result {
+        write_escaped(bytes, |s| self.write_text(s))
+    }
+}
+
+fn write_escaped(bytes: &[u8], mut write: impl FnMut(&str) -> fmt::Result) -> fmt::Result {
+    for b in bytes {
+        let high = digit(u32::from(b >> 4)).to_ascii_lowercase();
+        let hex = [b'\\', b'x', high, digit(u32::from(b & 0xf)).to_ascii_lowercase()];
+        let escaped = match b {
+            b'\r' => r"\r",
+            b'\n' => r"\n",
+            b'\t' => r"\t",
+            b'\\' => r"\\",
+            // A single ASCII byte
+            b' '..=b'~' => from_utf8_trusted(core::slice::from_ref(b)),
+            // Only ASCII bytes
+            _ => from_utf8_trusted(&hex),
+        };
+        write(escaped)?;
+    }
+    Ok(())
+}
+
+/// Bytes shown with all but printable ASCII characters escaped
+///
+/// The escapes are the same as with [ArrForm::write_escaped](crate::FormBuffer::write_escaped).
+/// ```
+/// use arrform::{arrform, Escaped};
+///
+/// let frame = [0x02, b'A', b'T', 0x03];
+/// assert_eq!(r"rx \x02AT\x03", arrform!(32, "rx {}", Escaped(&frame)).as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Escaped<'a>(pub &'a [u8]);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_escaped(self.0, |s| f.write_str(s))
+    }
+}
@@ -0,0 +1,214 @@
+//! Integer formatting without `core::fmt` and without division
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+const POW10_U32: [u32; 10] =
+    [1_000_000_000, 100_000_000, 10_000_000, 1_000_000, 100_000, 10_000, 1_000, 100, 10, 1];
+
+const POW10_U64: [u64; 20] = [
+    10_000_000_000_000_000_000, 1_000_000_000_000_000_000, 100_000_000_000_000_000,
+    10_000_000_000_000_000, 1_000_000_000_000_000, 100_000_000_000_000, 10_000_000_000_000,
+    1_000_000_000_000, 100_000_000_000, 10_000_000_000, 1_000_000_000, 100_000_000, 10_000_000,
+    1_000_000, 100_000, 10_000, 1_000, 100, 10, 1,
+];
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append an unsigned number in decimal notation
+    ///
+    /// The digits are found by subtracting powers of ten, so neither the `core::fmt` machinery
+    /// nor a hardware divide is needed.
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// for v in [0, 7, 10, 4711, u32::MAX] {
+    ///     af.clear();
+    ///     af.write_u32(v).unwrap();
+    ///     assert_eq!(arrform!(32, "{}", v).as_str(), af.as_str());
+    /// }
+    /// ```
+    pub fn write_u32(&mut self, v: u32) -> fmt::Result {
+        self.write_u32_signed(v, false)
+    }
+
+    /// Append a signed number in decimal notation
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_i32(i32::MIN).unwrap();
+    /// assert_eq!("-2147483648", af.as_str());
+    /// ```
+    pub fn write_i32(&mut self, v: i32) -> fmt::Result {
+        self.write_u32_signed(v.unsigned_abs(), v < 0)
+    }
+
+    /// Append an unsigned 64 bit number in decimal notation
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_u64(u64::MAX).unwrap();
+    /// assert_eq!("18446744073709551615", af.as_str());
+    /// ```
+    pub fn write_u64(&mut self, v: u64) -> fmt::Result {
+        self.write_u64_signed(v, false)
+    }
+
+    /// Append a signed 64 bit number in decimal notation
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// for v in [i64::MIN, -4711, -1, 0, 1, i64::MAX] {
+    ///     af.clear();
+    ///     af.write_i64(v).unwrap();
+    ///     assert_eq!(arrform!(32, "{}", v).as_str(), af.as_str());
+    /// }
+    /// ```
+    pub fn write_i64(&mut self, v: i64) -> fmt::Result {
+        self.write_u64_signed(v.unsigned_abs(), v < 0)
+    }
+
+    fn write_u32_signed(&mut self, v: u32, negative: bool) -> fmt::Result {
+        self.write_u32_digits(v, negative, 1)
+    }
+
+    /// Write at least `min_digits` digits, padded with leading zeros
+    pub(crate) fn write_u32_digits(
+        &mut self,
+        mut v: u32,
+        negative: bool,
+        min_digits: usize,
+    ) -> fmt::Result {
+        // Sign and 10 digits of u32::MAX
+        let mut digits = [0u8; 11];
+        let mut len = 0;
+        let mut slots = digits.iter_mut();
+        if negative {
+            if let Some(sign) = slots.next() {
+                *sign = b'-';
+                len = 1;
+            }
+        }
+        let last_pad = POW10_U32.len().saturating_sub(min_digits.max(1));
+        let first = POW10_U32.iter().position(|&p| p <= v).unwrap_or(last_pad).min(last_pad);
+        for (&pow, slot) in POW10_U32.iter().skip(first).zip(slots) {
+            let mut digit = b'0';
+            while v >= pow {
+                v -= pow;
+                digit += 1;
+            }
+            *slot = digit;
+            len += 1;
+        }
+        self.write_ascii(digits.get(..len).unwrap_or_default())
+    }
+
+    fn write_u64_signed(&mut self, mut v: u64, negative: bool) -> fmt::Result {
+        // Sign and 20 digits of u64::MAX
+        let mut digits = [0u8; 21];
+        let mut len = 0;
+        let mut slots = digits.iter_mut();
+        if negative {
+            if let Some(sign) = slots.next() {
+                *sign = b'-';
+                len = 1;
+            }
+        }
+        let first = POW10_U64.iter().position(|&p| p <= v).unwrap_or(POW10_U64.len() - 1);
+        for (&pow, slot) in POW10_U64.iter().skip(first).zip(slots) {
+            let mut digit = b'0';
+            while v >= pow {
+                v -= pow;
+                digit += 1;
+            }
+            *slot = digit;
+            len += 1;
+        }
+        self.write_ascii(digits.get(..len).unwrap_or_default())
+    }
+}
+
+/// A value, which [arrform_fast!](crate::arrform_fast!) appends without `core::fmt`
+///
+/// Text is cut at the last complete character, if it does not fit.
+/// ```
+/// use arrform::{ArrForm, FastArg};
+///
+/// let mut af = ArrForm::<3>::new();
+/// assert!("T=µ".write_to(&mut af).is_err());
+/// assert_eq!("T=", af.as_str());
+/// ```
+pub trait FastArg {
+    /// Append the value to the buffer
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result;
+}
+
+impl FastArg for &str {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_text(self)
+    }
+}
+
+impl FastArg for char {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_text(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+macro_rules! fast_arg {
+    ($method:ident, $wide:ty, $($t:ty),+) => {$(
+        impl FastArg for $t {
+            fn write_to<S: Storage, P: OverflowPolicy>(
+                &self,
+                af: &mut FormBuffer<S, P>,
+            ) -> fmt::Result {
+                af.$method(*self as $wide)
+            }
+        }
+    )+};
+}
+
+fast_arg!(write_u32, u32, u8, u16, u32);
+fast_arg!(write_i32, i32, i8, i16, i32);
+fast_arg!(write_u64, u64, u64, usize);
+fast_arg!(write_i64, i64, i64, isize);
+
+/// A macro to concatenate strings and integers without `core::fmt`
+///
+/// Each argument is a string, a character or an integer. Integers are appended with the
+/// division free routines like [ArrForm::write_u32](crate::FormBuffer::write_u32). This avoids
+/// the code size of the formatting machinery. The macro panics if the text does not fit into
+/// the buffer.
+///
+/// The arguments are listed one by one instead of using `{}` in a format string as with
+/// [arrform!](crate::arrform!). A `macro_rules!` macro cannot look into a string literal, and
+/// `format_args!` always dispatches through `core::fmt`, so `arrform!` has no way to route
+/// plain integer placeholders past the formatting machinery.
+/// ```
+/// use arrform::arrform_fast;
+///
+/// let mv: u32 = 3300;
+/// let temp: i8 = -12;
+/// let af = arrform_fast!(32, "U=", mv, "mV T=", temp, 'C');
+/// assert_eq!("U=3300mV T=-12C", af.as_str());
+/// ```
+#[macro_export]
+macro_rules! arrform_fast {
+    ($size:expr, $($arg:expr),+ $(,)?) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        let mut result: ::core::fmt::Result = Ok(());
+
+        // Stop at the first argument, which does not fit
+        $(
+            if result.is_ok() {
+                result = $crate::FastArg::write_to(&$arg, &mut af);
+            }
+        )+
+        result.expect("Buffer overflow");
+        af
+    }}
+}
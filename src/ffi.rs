@@ -0,0 +1,170 @@
+//! C interface
+
+// Raw pointers from C can only be read with unsafe code, also with the feature `safe`
+#![allow(unsafe_code)]
+
+use core::{
+    ffi::{c_char, c_int, CStr},
+    slice,
+};
+
+use crate::{ArrFormRef, PrintfArg, SaturatePolicy};
+
+/// Maximum number of arguments of [arrform_snprintf]
+pub const ARRFORM_MAX_ARGS: usize = 16;
+
+/// Argument of [arrform_snprintf], a value tagged with its kind
+///
+/// The matching C declaration is
+/// ```c
+/// enum { ARRFORM_INT, ARRFORM_UINT, ARRFORM_FLOAT, ARRFORM_STR, ARRFORM_CHAR };
+///
+/// typedef struct {
+///     uint32_t kind;
+///     union {
+///         int64_t int_;
+///         uint64_t uint;
+///         double float_;
+///         const char *str;
+///         uint32_t char_;
+///     } value;
+/// } arrform_arg;
+///
+/// int arrform_snprintf(char *buf, size_t len, const char *fmt,
+///                      const arrform_arg *args, size_t nargs);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArrFormCArg {
+    pub kind: u32,
+    pub value: ArrFormCValue,
+}
+
+/// Value of an [ArrFormCArg]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union ArrFormCValue {
+    pub int: i64,
+    pub uint: u64,
+    pub float: f64,
+    pub str: *const c_char,
+    pub char: u32,
+}
+
+impl ArrFormCArg {
+    pub const INT: u32 = 0;
+    pub const UINT: u32 = 1;
+    pub const FLOAT: u32 = 2;
+    pub const STR: u32 = 3;
+    pub const CHAR: u32 = 4;
+
+    /// Convert to an argument of [FormBuffer::printf](crate::FormBuffer::printf)
+    ///
+    /// # Safety
+    ///
+    /// The value must match the kind, a string must be a valid C string.
+    unsafe fn printf_arg(&self) -> Option<PrintfArg<'_>> {
+        Some(match self.kind {
+            Self::INT => PrintfArg::Int(self.value.int),
+            Self::UINT => PrintfArg::Uint(self.value.uint),
+            Self::FLOAT => PrintfArg::Float(self.value.float),
+            Self::STR if !self.value.str.is_null() => {
+                PrintfArg::Str(CStr::from_ptr(self.value.str).to_str().ok()?)
+            }
+            Self::CHAR => PrintfArg::Char(char::from_u32(self.value.char)?),
+            _ => return None,
+        })
+    }
+}
+
+/// Format into a C buffer, like `snprintf` with an argument array instead of varargs
+///
+/// The template is interpreted like [FormBuffer::printf](crate::FormBuffer::printf). At most
+/// `len - 1` bytes are written, followed by a terminating zero. Like `snprintf`, the length of
+/// the complete text is returned, a value of `len` or more means that the text was truncated.
+/// `-1` is returned if the template, the arguments or a string are invalid.
+/// ```
+/// use arrform::{arrform_snprintf, ArrFormCArg, ArrFormCValue};
+///
+/// let args = [
+///     ArrFormCArg { kind: ArrFormCArg::INT, value: ArrFormCValue { int: -4 } },
+///     ArrFormCArg { kind: ArrFormCArg::FLOAT, value: ArrFormCValue { float: 45.66 } },
+///     ArrFormCArg { kind: ArrFormCArg::STR, value: ArrFormCValue { str: c"ok".as_ptr() } },
+/// ];
+/// let mut buf = [0u8; 32];
+/// let fmt = c"T=%d C, RH=%.1f%% %s";
+/// let len = unsafe {
+///     arrform_snprintf(buf.as_mut_ptr().cast(), buf.len(), fmt.as_ptr(), args.as_ptr(), 3)
+/// };
+/// assert_eq!(19, len);
+/// assert_eq!(b"T=-4 C, RH=45.7% ok\0", &buf[..20]);
+///
+/// let mut buf = [0u8; 8];
+/// let len = unsafe {
+///     arrform_snprintf(buf.as_mut_ptr().cast(), buf.len(), fmt.as_ptr(), args.as_ptr(), 3)
+/// };
+/// assert_eq!(19, len);
+/// assert_eq!(b"T=-4 C,\0", &buf);
+///
+/// // Templates from C are not trusted, huge widths are limited and cannot hang or panic
+/// let fmt = c"%99999999999999999999999d";
+/// let len = unsafe {
+///     arrform_snprintf(buf.as_mut_ptr().cast(), buf.len(), fmt.as_ptr(), args.as_ptr(), 1)
+/// };
+/// assert_eq!(65535, len);
+/// assert_eq!(b"       \0", &buf);
+/// let len = unsafe {
+///     arrform_snprintf(core::ptr::null_mut(), 0, c"%".as_ptr(), args.as_ptr(), 0)
+/// };
+/// assert_eq!(-1, len);
+/// ```
+///
+/// # Safety
+///
+/// `buf` must be valid for `len` bytes, or null if `len` is zero. `fmt` must be a valid C
+/// string and `args` must point to `nargs` arguments, whose values match their kinds.
+#[no_mangle]
+pub unsafe extern "C" fn arrform_snprintf(
+    buf: *mut c_char,
+    len: usize,
+    fmt: *const c_char,
+    args: *const ArrFormCArg,
+    nargs: usize,
+) -> c_int {
+    if fmt.is_null() || nargs > ARRFORM_MAX_ARGS || (args.is_null() && nargs > 0) {
+        return -1;
+    }
+    let Ok(template) = CStr::from_ptr(fmt).to_str() else {
+        return -1;
+    };
+    let mut converted = [PrintfArg::Int(0); ARRFORM_MAX_ARGS];
+    if nargs > 0 {
+        for (arg, c_arg) in converted.iter_mut().zip(slice::from_raw_parts(args, nargs)) {
+            match c_arg.printf_arg() {
+                Some(v) => *arg = v,
+                None => return -1,
+            }
+        }
+    }
+
+    let mut empty = [];
+    let storage: &mut [u8] = if buf.is_null() || len == 0 {
+        &mut empty
+    } else {
+        slice::from_raw_parts_mut(buf.cast(), len)
+    };
+    // Keep one byte for the terminating zero
+    let text_len = storage.len().saturating_sub(1);
+    let (text, _) = storage.split_at_mut(text_len);
+    let mut af = ArrFormRef::<SaturatePolicy>::from_storage(text);
+    if af.printf(template, converted.get(..nargs).unwrap_or_default()).is_err() {
+        return -1;
+    }
+    let used = af.len();
+    // Saturates, so a hostile template cannot overflow the count
+    let needed = used.saturating_add(af.dropped);
+    if len > 0 && !buf.is_null() {
+        *buf.add(used) = 0;
+    }
+    c_int::try_from(needed).unwrap_or(-1)
+}
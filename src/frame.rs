@@ -0,0 +1,129 @@
+//! Builder for binary frames with embedded text
+
+use core::fmt;
+
+use crate::ArrForm;
+
+/// Assembles binary frames of up to `N` bytes, which may contain formatted text sections
+///
+/// Numbers are appended in the given byte order. Each value is written completely or not at
+/// all, so a frame never contains half a number. Text is formatted with `write!`.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::ArrBuf;
+///
+/// let mut frame = ArrBuf::<12>::new();
+/// frame.put_u8(0x02).unwrap();
+/// frame.put_u16_le(0).unwrap();
+/// write!(frame, "T={}", 21).unwrap();
+/// let len = frame.len() as u16 - 3;
+/// frame.set_u16_le(1, len).unwrap();
+/// assert_eq!(b"\x02\x04\x00T=21", frame.as_bytes());
+///
+/// assert!(frame.put_u32_be(0xdead_beef).is_ok());
+/// assert!(frame.put_u32_be(0xdead_beef).is_err());
+/// assert_eq!(11, frame.len());
+/// ```
+#[derive(Clone, Default)]
+pub struct ArrBuf<const N: usize> {
+    form: ArrForm<N>,
+}
+
+impl<const N: usize> ArrBuf<N> {
+    /// Creates an empty frame
+    pub const fn new() -> Self {
+        ArrBuf { form: ArrForm::new() }
+    }
+
+    /// Append a byte
+    pub fn put_u8(&mut self, v: u8) -> fmt::Result {
+        self.put_slice(&[v])
+    }
+
+    /// Append a 16 bit number, least significant byte first
+    pub fn put_u16_le(&mut self, v: u16) -> fmt::Result {
+        self.put_slice(&v.to_le_bytes())
+    }
+
+    /// Append a 16 bit number, most significant byte first
+    pub fn put_u16_be(&mut self, v: u16) -> fmt::Result {
+        self.put_slice(&v.to_be_bytes())
+    }
+
+    /// Append a 32 bit number, least significant byte first
+    pub fn put_u32_le(&mut self, v: u32) -> fmt::Result {
+        self.put_slice(&v.to_le_bytes())
+    }
+
+    /// Append a 32 bit number, most significant byte first
+    pub fn put_u32_be(&mut self, v: u32) -> fmt::Result {
+        self.put_slice(&v.to_be_bytes())
+    }
+
+    /// Append bytes, nothing is written if they do not fit
+    pub fn put_slice(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.form.try_reserve(bytes.len())?;
+        self.form.write_bytes(bytes)
+    }
+
+    /// Overwrite a 16 bit number at byte position `offset`, e.g. a length field
+    pub fn set_u16_le(&mut self, offset: usize, v: u16) -> fmt::Result {
+        self.set_slice(offset, &v.to_le_bytes())
+    }
+
+    /// Overwrite a 16 bit number at byte position `offset`, e.g. a length field
+    pub fn set_u16_be(&mut self, offset: usize, v: u16) -> fmt::Result {
+        self.set_slice(offset, &v.to_be_bytes())
+    }
+
+    /// Overwrite bytes at byte position `offset`, which have been written before
+    pub fn set_slice(&mut self, offset: usize, bytes: &[u8]) -> fmt::Result {
+        let end = offset.checked_add(bytes.len()).ok_or(fmt::Error)?;
+        self.form.raw = true;
+        let dst = self.form.filled_mut().get_mut(offset..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Get the content of the frame
+    pub fn as_bytes(&self) -> &[u8] {
+        self.form.as_bytes()
+    }
+
+    /// Get the length of the frame in bytes
+    pub fn len(&self) -> usize {
+        self.form.len()
+    }
+
+    /// Returns `true` if the frame has no content
+    pub fn is_empty(&self) -> bool {
+        self.form.is_empty()
+    }
+
+    /// Get the size of the buffer in bytes
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Get the number of bytes, which are still free
+    pub fn remaining(&self) -> usize {
+        self.form.remaining()
+    }
+
+    /// Remove the content, so the buffer can be reused
+    pub fn clear(&mut self) {
+        self.form.clear();
+    }
+}
+
+impl<const N: usize> fmt::Write for ArrBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.put_slice(s.as_bytes())
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_bytes(), f)
+    }
+}
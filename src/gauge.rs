@@ -0,0 +1,56 @@
+//! Text progress bars for serial consoles and character displays
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a progress bar with `width` cells followed by the percentage
+    ///
+    /// `value` is limited to `max`. A `max` of 0 shows an empty bar.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_gauge(52, 100, 10).unwrap();
+    /// assert_eq!("[#####.....] 52%", af.as_str());
+    /// ```
+    pub fn write_gauge(&mut self, value: u32, max: u32, width: usize) -> fmt::Result {
+        self.write_gauge_with(value, max, width, '#', '.')
+    }
+
+    /// Append a progress bar like [ArrForm::write_gauge](crate::FormBuffer::write_gauge) with
+    /// own characters for the filled and the empty cells
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_gauge_with(3, 4, 8, '█', '░').unwrap();
+    /// assert_eq!("[██████░░] 75%", af.as_str());
+    /// ```
+    pub fn write_gauge_with(
+        &mut self,
+        value: u32,
+        max: u32,
+        width: usize,
+        filled: char,
+        empty: char,
+    ) -> fmt::Result {
+        let value = value.min(max);
+        let (cells, percent) = match max {
+            0 => (0, 0),
+            _ => (
+                (value as u64 * width as u64 / max as u64) as usize,
+                (value as u64 * 100 / max as u64) as u32,
+            ),
+        };
+
+        let mut encoded = [0u8; 4];
+        self.write_ascii(b"[")?;
+        self.write_repeated(filled.encode_utf8(&mut encoded), cells)?;
+        self.write_repeated(empty.encode_utf8(&mut encoded), width - cells)?;
+        self.write_ascii(b"] ")?;
+        self.put_u32(percent)?;
+        self.write_ascii(b"%")
+    }
+}
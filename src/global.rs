@@ -0,0 +1,75 @@
+//! Global formatter for `print!`-like output
+
+use core::{cell::RefCell, fmt};
+
+use critical_section::Mutex;
+
+use crate::ArrForm;
+
+/// Size of the global buffer used by [arrprint!](crate::arrprint!)
+pub const PRINT_SIZE: usize = 128;
+
+struct Printer {
+    buffer: ArrForm<PRINT_SIZE>,
+    output: Option<fn(&[u8])>,
+}
+
+static PRINTER: Mutex<RefCell<Printer>> =
+    Mutex::new(RefCell::new(Printer { buffer: ArrForm::new(), output: None }));
+
+/// Register the function, which receives the text of [arrprint!](crate::arrprint!)
+///
+/// Until a function is registered, the text is discarded.
+pub fn set_print_output(output: fn(&[u8])) {
+    critical_section::with(|cs| PRINTER.borrow_ref_mut(cs).output = Some(output));
+}
+
+#[doc(hidden)]
+pub fn _print(args: fmt::Arguments, eol: &str) {
+    critical_section::with(|cs| {
+        let mut printer = PRINTER.borrow_ref_mut(cs);
+        let printer = &mut *printer;
+        if let Some(output) = printer.output {
+            // Text too long is clipped, but the line ending is kept
+            let limit = PRINT_SIZE.saturating_sub(eol.len());
+            let _ = printer.buffer.format_limited(limit, args);
+            let _ = printer.buffer.push_str(eol);
+            output(printer.buffer.as_bytes());
+            printer.buffer.clear();
+        }
+    });
+}
+
+/// Format numbers and strings and pass them to the registered output function
+///
+/// The text is formatted into a global buffer of [PRINT_SIZE] bytes, guarded by a critical
+/// section. Text that does not fit is clipped. The output function is registered with
+/// [set_print_output].
+/// ```
+/// use arrform::{arrprint, arrprintln, set_print_output};
+///
+/// fn uart_send(bytes: &[u8]) {
+///     assert!(bytes.starts_with(b"T="));
+/// }
+///
+/// set_print_output(uart_send);
+/// arrprint!("T={:.1}", 21.56);
+/// arrprintln!("T={:.1}", 22.04);
+/// ```
+#[macro_export]
+macro_rules! arrprint {
+    ($($arg:tt)*) => {
+        $crate::_print(format_args!($($arg)*), "")
+    }
+}
+
+/// A macro like [arrprint!], which appends `"\r\n"`
+#[macro_export]
+macro_rules! arrprintln {
+    () => {
+        $crate::_print(format_args!(""), "\r\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::_print(format_args!($($arg)*), "\r\n")
+    };
+}
@@ -0,0 +1,83 @@
+//! Integers with thousands separators
+
+use core::fmt;
+
+use crate::{utf8::from_utf8_trusted, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append an integer with `separator` between groups of three digits
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_grouped(1234567, ' ').unwrap();
+    /// assert_eq!("1 234 567", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_grouped(-123456, ',').unwrap();
+    /// assert_eq!("-123,456", af.as_str());
+    /// ```
+    pub fn write_grouped(&mut self, v: i64, separator: char) -> fmt::Result {
+        write_grouped(v, separator, |s| self.write_text(s))
+    }
+}
+
+fn write_grouped(
+    v: i64,
+    separator: char,
+    mut write: impl FnMut(&str) -> fmt::Result,
+) -> fmt::Result {
+    // Sign and 19 digits of i64::MIN, filled from the end
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    let mut abs = v.unsigned_abs();
+    for digit in digits.iter_mut().rev() {
+        start -= 1;
+        *digit = b'0' + (abs % 10) as u8;
+        abs /= 10;
+        if abs == 0 {
+            break;
+        }
+    }
+    if v < 0 {
+        write("-")?;
+    }
+
+    let mut encoded = [0u8; 4];
+    let separator: &str = separator.encode_utf8(&mut encoded);
+    // Only ASCII digits have been written
+    let digits = from_utf8_trusted(digits.get(start..).unwrap_or_default());
+    let first = match digits.len() % 3 {
+        0 => 3,
+        n => n,
+    };
+    write(digits.get(..first).unwrap_or_default())?;
+    for group in (first..digits.len()).step_by(3) {
+        write(separator)?;
+        write(digits.get(group..group + 3).unwrap_or_default())?;
+    }
+    Ok(())
+}
+
+/// An integer shown with a separator between groups of three digits
+/// ```
+/// use arrform::{arrform, Grouped};
+///
+/// let af = arrform!(32, "{} pcs", Grouped(1234567, '.'));
+/// assert_eq!("1.234.567 pcs", af.as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grouped(pub i64, pub char);
+
+impl fmt::Display for Grouped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_grouped(self.0, self.1, |s| f.write_str(s))
+    }
+}
+
+#[cfg(feature = "fast-int")]
+impl crate::FastArg for Grouped {
+    fn write_to<S: Storage, P: OverflowPolicy>(&self, af: &mut FormBuffer<S, P>) -> fmt::Result {
+        af.write_grouped(self.0, self.1)
+    }
+}
@@ -0,0 +1,61 @@
+//! Interoperability with the `heapless` crate
+
+use core::str::from_utf8;
+
+use crate::{ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Copy the content into a `heapless::String` with a capacity of `N` bytes
+    ///
+    /// An error is returned if the content does not fit into the string.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let s: heapless::String<8> = arrform!(16, "int {}", 42).to_heapless_string().unwrap();
+    /// assert_eq!("int 42", s);
+    /// assert!(arrform!(16, "int {}", 4711).to_heapless_string::<4>().is_err());
+    /// ```
+    pub fn to_heapless_string<const N: usize>(&self) -> Result<heapless::String<N>, ArrFormError> {
+        heapless::String::try_from(self.as_str()).map_err(|_| ArrFormError::Overflow {
+            capacity: N,
+            needed_at_least: self.used,
+        })
+    }
+}
+
+/// Creates new buffer from a `heapless::String` of the same capacity
+/// ```
+/// use arrform::ArrForm;
+///
+/// let s: heapless::String<8> = heapless::String::try_from("ready").unwrap();
+/// assert_eq!("ready", ArrForm::<8>::from(s));
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> From<heapless::String<BUF_SIZE>>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    fn from(s: heapless::String<BUF_SIZE>) -> Self {
+        // Cannot fail, the string is not longer than the buffer
+        Self::try_from(s.as_str()).unwrap_or_default()
+    }
+}
+
+/// Creates new buffer from text given as `heapless::Vec` of bytes
+///
+/// [ArrFormError::Fmt] is returned if the bytes are not valid UTF-8.
+/// ```
+/// use arrform::ArrForm;
+///
+/// let v: heapless::Vec<u8, 8> = heapless::Vec::from_slice(b"4711").unwrap();
+/// assert_eq!("4711", ArrForm::<4>::try_from(&v).unwrap());
+/// assert!(ArrForm::<3>::try_from(&v).is_err());
+/// ```
+impl<const BUF_SIZE: usize, const N: usize, P: OverflowPolicy> TryFrom<&heapless::Vec<u8, N>>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    type Error = ArrFormError;
+
+    fn try_from(v: &heapless::Vec<u8, N>) -> Result<Self, Self::Error> {
+        let s = from_utf8(v.as_slice()).map_err(|_| ArrFormError::Fmt)?;
+        Self::try_from(s)
+    }
+}
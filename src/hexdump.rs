@@ -0,0 +1,92 @@
+//! Hexdumps of byte slices
+
+use core::fmt;
+
+use crate::{ArrForm, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a hexdump of `data` with offset, hex bytes and ASCII column
+    ///
+    /// Each line shows `bytes_per_line` bytes and ends with `"\r\n"`. Bytes outside the
+    /// printable ASCII range are shown as `.` in the ASCII column.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<128>::new();
+    /// af.write_hexdump(b"Hello\x00 radio", 8).unwrap();
+    /// assert_eq!(
+    ///     "00000000: 48 65 6C 6C 6F 00 20 72  Hello. r\r\n\
+    ///      00000008: 61 64 69 6F              adio\r\n",
+    ///     af.as_str()
+    /// );
+    /// ```
+    pub fn write_hexdump(&mut self, data: &[u8], bytes_per_line: usize) -> fmt::Result {
+        let bytes_per_line = bytes_per_line.max(1);
+        for (line, chunk) in data.chunks(bytes_per_line).enumerate() {
+            self.write_hexdump_line(line.saturating_mul(bytes_per_line), chunk, bytes_per_line)?;
+            self.write_ascii(b"\r\n")?;
+        }
+        Ok(())
+    }
+
+    fn write_hexdump_line(&mut self, offset: usize, chunk: &[u8], width: usize) -> fmt::Result {
+        self.write_hex(offset as u32, 8)?;
+        self.write_ascii(b":")?;
+        for &byte in chunk {
+            self.write_ascii(b" ")?;
+            self.write_hex(byte as u32, 2)?;
+        }
+        self.write_repeated("   ", width.saturating_sub(chunk.len()))?;
+        self.write_ascii(b"  ")?;
+        for &byte in chunk {
+            let shown = if byte.is_ascii_graphic() || byte == b' ' { byte } else { b'.' };
+            self.write_ascii(&[shown])?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the lines of a hexdump, each formatted into a buffer of `N` bytes
+///
+/// The lines have the layout of [ArrForm::write_hexdump](crate::FormBuffer::write_hexdump),
+/// but no line ending. A line too long for the buffer is clipped.
+/// ```
+/// use arrform::HexDump;
+///
+/// let mut lines = HexDump::<64>::new(b"\x01\x02\x03ABC", 4);
+/// assert_eq!("00000000: 01 02 03 41  ...A", lines.next().unwrap().as_str());
+/// assert_eq!("00000004: 42 43        BC", lines.next().unwrap().as_str());
+/// assert!(lines.next().is_none());
+///
+/// let mut lines = HexDump::<64>::new(b"AB", usize::MAX);
+/// assert!(lines.next().unwrap().as_str().starts_with("00000000: 41 42"));
+/// assert!(lines.next().is_none());
+/// ```
+pub struct HexDump<'a, const N: usize> {
+    data: &'a [u8],
+    offset: usize,
+    bytes_per_line: usize,
+}
+
+impl<'a, const N: usize> HexDump<'a, N> {
+    /// Creates an iterator over the hexdump of `data`
+    pub fn new(data: &'a [u8], bytes_per_line: usize) -> Self {
+        HexDump { data, offset: 0, bytes_per_line: bytes_per_line.max(1) }
+    }
+}
+
+impl<const N: usize> Iterator for HexDump<'_, N> {
+    type Item = ArrForm<N>;
+
+    fn next(&mut self) -> Option<ArrForm<N>> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let end = self.offset.saturating_add(self.bytes_per_line).min(self.data.len());
+        let chunk = self.data.get(self.offset..end)?;
+        let mut af = ArrForm::new();
+        let _ = af.write_hexdump_line(self.offset, chunk, self.bytes_per_line);
+        self.offset = end;
+        Some(af)
+    }
+}
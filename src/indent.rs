@@ -0,0 +1,59 @@
+//! Indenting the lines of formatted text
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Writes an indent in front of every line
+///
+/// The indent is written lazily before the first character of a line, so empty lines and the
+/// end of the text carry no trailing spaces. Writers can be nested for deeper levels.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::{ArrForm, Indented};
+///
+/// let mut af = ArrForm::<64>::new();
+/// writeln!(af, "menu").unwrap();
+/// let mut level1 = af.indented("  ");
+/// writeln!(level1, "net").unwrap();
+/// writeln!(level1, "wifi:").unwrap();
+/// writeln!(Indented::new(&mut level1, "  "), "ssid={}\nmode={}", "home", 2).unwrap();
+/// assert_eq!("menu\n  net\n  wifi:\n    ssid=home\n    mode=2\n", af.as_str());
+/// ```
+pub struct Indented<'a, W: fmt::Write> {
+    inner: &'a mut W,
+    indent: &'a str,
+    line_start: bool,
+}
+
+impl<'a, W: fmt::Write> Indented<'a, W> {
+    /// Creates a writer, which indents the lines written to `inner`
+    ///
+    /// The writer assumes to start at the beginning of a line.
+    pub fn new(inner: &'a mut W, indent: &'a str) -> Self {
+        Indented { inner, indent, line_start: true }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a writer, which appends to this buffer and indents every following line
+    ///
+    /// The current line is indented as well, if the content is empty or ends with a newline.
+    pub fn indented<'a>(&'a mut self, indent: &'a str) -> Indented<'a, Self> {
+        let line_start = self.is_empty() || self.as_str().ends_with('\n');
+        Indented { inner: self, indent, line_start }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for Indented<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if self.line_start && line != "\n" {
+                self.inner.write_str(self.indent)?;
+            }
+            self.inner.write_str(line)?;
+            self.line_start = line.ends_with('\n');
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+//! JSON strings and a minimal JSON builder
+
+use core::fmt;
+
+use crate::{radix::digit, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append `s` as quoted JSON string
+    ///
+    /// Quotes, backslashes and control characters are escaped.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_json_str("say \"hi\"\n\x01").unwrap();
+    /// assert_eq!(r#""say \"hi\"\n\u0001""#, af.as_str());
+    ///
+    /// let mut af = ArrForm::<2>::new();
+    /// assert!(af.write_json_str("°C").is_err());
+    /// assert_eq!("\"", af.as_str());
+    /// ```
+    pub fn write_json_str(&mut self, s: &str) -> fmt::Result {
+        self.write_ascii(b"\"")?;
+        let mut start = 0;
+        for (i, &b) in s.as_bytes().iter().enumerate() {
+            let hex;
+            let escape: &[u8] = match b {
+                b'"' => b"\\\"",
+                b'\\' => b"\\\\",
+                b'\n' => b"\\n",
+                b'\r' => b"\\r",
+                b'\t' => b"\\t",
+                0..=0x1f => {
+                    let low = digit(u32::from(b & 0xf)).to_ascii_lowercase();
+                    hex = [b'\\', b'u', b'0', b'0', digit(u32::from(b >> 4)), low];
+                    &hex
+                }
+                _ => continue,
+            };
+            self.write_text(s.get(start..i).unwrap_or_default())?;
+            self.write_ascii(escape)?;
+            start = i + 1;
+        }
+        self.write_text(s.get(start..).unwrap_or_default())?;
+        self.write_ascii(b"\"")
+    }
+
+    /// Get a builder, which appends JSON to this buffer
+    pub fn json(&mut self) -> JsonForm<'_, S, P> {
+        JsonForm { buffer: self, comma: false, result: Ok(()) }
+    }
+}
+
+/// Builder for JSON text in a buffer
+///
+/// The calls are chained and commas are inserted as needed. The first error is kept and
+/// returned by [JsonForm::finish], later calls do nothing.
+/// ```
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<64>::new();
+/// af.json()
+///     .object()
+///     .key("t").value_f32(22.5)
+///     .key("id").value_str("room \"1\"")
+///     .key("on").value_bool(true)
+///     .key("v").array().value_i64(1).value_i64(-2).end_array()
+///     .end_object()
+///     .finish()
+///     .unwrap();
+/// assert_eq!(r#"{"t":22.5,"id":"room \"1\"","on":true,"v":[1,-2]}"#, af.as_str());
+///
+/// let mut af = ArrForm::<8>::new();
+/// assert!(af.json().object().key("temperature").value_f32(22.5).finish().is_err());
+/// ```
+pub struct JsonForm<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+    comma: bool,
+    result: fmt::Result,
+}
+
+impl<S: Storage, P: OverflowPolicy> JsonForm<'_, S, P> {
+    /// Open an object
+    pub fn object(&mut self) -> &mut Self {
+        self.value_with(|af| af.write_ascii(b"{"));
+        self.comma = false;
+        self
+    }
+
+    /// Close an object
+    pub fn end_object(&mut self) -> &mut Self {
+        self.write(|af| af.write_ascii(b"}"));
+        self.comma = true;
+        self
+    }
+
+    /// Open an array
+    pub fn array(&mut self) -> &mut Self {
+        self.value_with(|af| af.write_ascii(b"["));
+        self.comma = false;
+        self
+    }
+
+    /// Close an array
+    pub fn end_array(&mut self) -> &mut Self {
+        self.write(|af| af.write_ascii(b"]"));
+        self.comma = true;
+        self
+    }
+
+    /// Write the key of the next value in an object
+    pub fn key(&mut self, key: &str) -> &mut Self {
+        self.value_with(|af| af.write_json_str(key).and_then(|_| af.write_ascii(b":")));
+        self.comma = false;
+        self
+    }
+
+    /// Write a string value
+    pub fn value_str(&mut self, v: &str) -> &mut Self {
+        self.value_with(|af| af.write_json_str(v))
+    }
+
+    /// Write an integer value
+    pub fn value_i64(&mut self, v: i64) -> &mut Self {
+        self.value_with(|af| af.append(format_args!("{}", v)))
+    }
+
+    /// Write a float value, `NaN` and infinity are written as `null`
+    pub fn value_f32(&mut self, v: f32) -> &mut Self {
+        if v.is_finite() {
+            self.value_with(|af| af.append(format_args!("{}", v)))
+        } else {
+            self.value_null()
+        }
+    }
+
+    /// Write a boolean value
+    pub fn value_bool(&mut self, v: bool) -> &mut Self {
+        self.value_with(|af| af.write_ascii(if v { b"true" } else { b"false" }))
+    }
+
+    /// Write `null`
+    pub fn value_null(&mut self) -> &mut Self {
+        self.value_with(|af| af.write_ascii(b"null"))
+    }
+
+    /// Write formatted text unchanged as value, e.g. a number with a fixed number of decimals
+    pub fn value_fmt(&mut self, args: fmt::Arguments) -> &mut Self {
+        self.value_with(|af| af.append(args))
+    }
+
+    /// Get the result of all calls
+    pub fn finish(&mut self) -> fmt::Result {
+        self.result
+    }
+
+    fn value_with<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut FormBuffer<S, P>) -> fmt::Result,
+    {
+        if self.comma {
+            self.write(|af| af.write_ascii(b","));
+        }
+        self.write(f);
+        self.comma = true;
+        self
+    }
+
+    fn write<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut FormBuffer<S, P>) -> fmt::Result,
+    {
+        if self.result.is_ok() {
+            self.result = f(self.buffer);
+        }
+    }
+}
@@ -1,4 +1,10 @@
 #![no_std]
+#![cfg_attr(all(feature = "safe", not(feature = "ffi")), forbid(unsafe_code))]
+#![cfg_attr(all(feature = "safe", feature = "ffi"), deny(unsafe_code))]
+#![cfg_attr(
+    feature = "panic-free",
+    deny(clippy::panic, clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)
+)]
 
 //! String formatting without memory allocator
 //! ==========================================
@@ -33,16 +39,271 @@
 //! formatted (30,928 bytes additional). The program code used to determine these numbers can be 
 //! found in the example directory.
 //! 
+//! # Features
+//! 
+//! Optional integrations are enabled with cargo features:
+//! 
+//! * `ufmt`: implements `ufmt::uWrite` for the buffers and adds the `arrform_u!` macro
+//! * `defmt`: implements `defmt::Format` for the buffers
+//! * `alloc`: converts the buffers from and into `String`
+//! * `fast-int`: adds `write_u32`, `write_i64` and friends, which format integers without 
+//!   `core::fmt` and without division, plus the `arrform_fast!` macro
+//! * `auto`: adds the `arrform_auto!` macro, which computes the buffer size at compile time
+//! * `derive`: adds `#[derive(ArrDisplay)]`, which writes structs and enums without 
+//!   `core::fmt`
+//! * `compact-float`: adds `write_f32` and `write_exp`, small replacements for the `{:.N}` and 
+//!   `{:e}` formatting of floats, and `Float` for the `arrform_fast!` macro
+//! * `critical-section`: adds the `arrprint!` and `arrprintln!` macros, which format into a 
+//!   global buffer and pass the text to a registered output function
+//! * `embedded-io`: implements `embedded_io::Write` for the buffers
+//! * `embedded-hal`: adds [FmtSink] and the `serform!` macro to format straight to a serial 
+//!   port implementing `embedded_hal::serial::Write`
+//! * `embedded-graphics`: adds `TextPanel`, a scrolling panel of text lines, which is drawn 
+//!   with `embedded_graphics::text::Text`
+//! * `rtt-target`: adds `ArrForm::write_rtt` and the `rtt_arrform!` macro to send text to an 
+//!   RTT up channel
+//! * `ffi`: exports `arrform_snprintf`, which lets C code format with the runtime templates of 
+//!   [FormBuffer::printf]
+//! * `panic-free`: denies the clippy lints `panic`, `unwrap_used`, `expect_used` and 
+//!   `indexing_slicing` for the crate, so `cargo clippy --features panic-free` checks that no 
+//!   function of the crate panics by `panic!`, `unwrap`, `expect` or indexing. The lints do not 
+//!   cover integer arithmetic, so they do not rule out an overflow check of a debug build. 
+//!   The only exceptions panic on purpose: 
+//!   [PanicPolicy] and [OverflowMode::Panic], the const functions, which fail at compile time, 
+//!   and the macros `arrform!`, `arrformln!`, `arrform_concat!`, `arrform_fast!` and 
+//!   `arrform_u!`, which panic in the calling code. Use `try_arrform!` or `arrform_lossy!` 
+//!   instead.
+//! * `safe`: forbids unsafe code in the crate, for projects and audits, which require 
+//!   `#![forbid(unsafe_code)]` in all dependencies. The content is validated as UTF-8 each 
+//!   time it is read as str, which costs some speed. `FormPool`, `arrform_static!`, 
+//!   `write_utf8_unchecked` and `as_bytes_mut` are not available. Together with `ffi`, unsafe 
+//!   code is denied except for the C interface.
+//! * `log`: adds `ArrLogger`, a `log` backend which passes formatted records to a function
+//! * `std`: implements `std::io::Write` for the buffers and converts [ArrFormError] into 
+//!   `std::io::Error`
+//! * `heapless`: converts the buffers from and into `heapless::String` and from 
+//!   `heapless::Vec<u8, N>`
+//! * `arrayvec`: converts the buffers from and into `arrayvec::ArrayString` and 
+//!   `arrayvec::ArrayVec<u8, N>`
+//! * `serde`: implements `Serialize` and `Deserialize` for the buffers, a string too long for 
+//!   the buffer fails to deserialize
+//! 
 //! # License
 //! 
 //! Apache version 2.0 or Mit
 //!
-use core::{fmt, str::from_utf8_unchecked};
-use core::mem::MaybeUninit;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::{
+    borrow::Borrow, ffi::CStr, fmt, hash::{Hash, Hasher}, marker::PhantomData,
+    ops::{Bound, Deref, RangeBounds},
+    str::{
+        from_utf8, CharIndices, Chars, FromStr, Lines, Utf8Error,
+    },
+};
+
+use storage::Token;
+use utf8::{from_utf8_trusted, from_utf8_trusted_mut, valid_prefix, valid_prefix_mut};
+
+#[cfg(feature = "alloc")]
+mod alloc_impl;
+mod ansi;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impl;
+mod at;
+mod base64;
+mod charset;
+mod checksum;
+mod collect;
+#[cfg(feature = "compact-float")]
+mod compact_float;
+mod const_fmt;
+mod counting;
+mod csv;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+mod display;
+mod double;
+mod duration;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impl;
+mod error;
+mod escape;
+#[cfg(feature = "fast-int")]
+mod fast_int;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod frame;
+mod gauge;
+#[cfg(feature = "critical-section")]
+mod global;
+mod group;
+#[cfg(feature = "heapless")]
+mod heapless_impl;
+mod hexdump;
+mod indent;
+mod json;
+#[cfg(feature = "log")]
+mod log_impl;
+mod marquee;
+mod net;
+mod nmea;
+mod overwrite;
+#[cfg(feature = "embedded-graphics")]
+mod panel;
+mod policy;
+#[cfg(not(feature = "safe"))]
+mod pool;
+mod printf;
+mod radix;
+mod ring;
+#[cfg(feature = "rtt-target")]
+mod rtt_impl;
+mod segmented;
+#[cfg(feature = "embedded-hal")]
+mod serial;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod si;
+mod slice;
+mod split;
+#[cfg(feature = "std")]
+mod std_impl;
+mod storage;
+mod stream;
+mod table;
+mod tee;
+mod time;
+#[cfg(feature = "ufmt")]
+mod ufmt_impl;
+mod url;
+mod utf16;
+mod utf8;
+mod width;
+mod wrap;
+pub use ansi::Color;
+pub use at::AtForm;
+/// A macro like [arrform!], which computes a sufficient buffer size at compile time
+/// 
+/// Each argument is given with its type, like `id: u32`. The size of the buffer is the length 
+/// of the literal text plus the worst case length of each placeholder, so the macro can never 
+/// overflow. Integers, floats, `bool` and `char` are supported. Strings are given as `str<N>` 
+/// and clipped to at most `N` bytes.
+/// 
+/// ```
+/// use arrform::arrform_auto;
+/// 
+/// let temp = 21.56;
+/// let af = arrform_auto!("id {:04X}: {:.1} C {}", 0x2a: u16, temp: f32, "ok": str<8>);
+/// assert_eq!("id 002A: 21.6 C ok", af.as_str());
+/// assert_eq!(8 + 4 + 42 + 8, af.capacity());
+/// ```
+#[cfg(feature = "auto")]
+pub use arrform_macros::arrform_auto;
+pub use base64::Base64;
+pub use charset::Charset;
+pub use checksum::Checksummed;
+#[cfg(feature = "compact-float")]
+pub use compact_float::Float;
+pub use counting::CountingForm;
+pub use csv::CsvForm;
+pub use display::ArrDisplay;
+/// Derive [ArrDisplay](trait@ArrDisplay) for structs and enums
+/// 
+/// ```
+/// use arrform::{ArrDisplay, ArrForm};
+/// 
+/// #[derive(ArrDisplay)]
+/// enum Mode {
+///     Off,
+///     Auto(u8),
+/// }
+/// 
+/// #[derive(ArrDisplay)]
+/// struct Config {
+///     baud: u32,
+///     name: &'static str,
+///     mode: Mode,
+///     limits: [i16; 2],
+/// }
+/// 
+/// let config = Config { baud: 9600, name: "uart0", mode: Mode::Auto(3), limits: [-5, 40] };
+/// let mut af = ArrForm::<80>::new();
+/// af.write_display(&config).unwrap();
+/// assert_eq!(
+///     r#"Config { baud: 9600, name: "uart0", mode: Auto(3), limits: [-5, 40] }"#,
+///     af.as_str()
+/// );
+/// 
+/// af.clear();
+/// af.write_display(&Mode::Off).unwrap();
+/// assert_eq!("Off", af.as_str());
+/// ```
+#[cfg(feature = "derive")]
+pub use arrform_macros::ArrDisplay;
+pub use double::DoubleBuffer;
+pub use duration::DurationStyle;
+pub use error::ArrFormError;
+pub use escape::Escaped;
+#[cfg(feature = "fast-int")]
+pub use fast_int::FastArg;
+#[cfg(feature = "ffi")]
+pub use ffi::{arrform_snprintf, ArrFormCArg, ArrFormCValue, ARRFORM_MAX_ARGS};
+pub use frame::ArrBuf;
+#[cfg(feature = "critical-section")]
+pub use global::{_print, set_print_output, PRINT_SIZE};
+pub use group::Grouped;
+pub use hexdump::HexDump;
+pub use indent::Indented;
+pub use json::JsonForm;
+#[cfg(feature = "log")]
+pub use log_impl::ArrLogger;
+pub use marquee::Marquee;
+pub use nmea::NmeaForm;
+#[cfg(feature = "embedded-graphics")]
+pub use panel::TextPanel;
+pub use policy::{
+    ErrorPolicy, OverflowMode, OverflowPolicy, PanicPolicy, RuntimePolicy, SaturatePolicy,
+};
+#[cfg(not(feature = "safe"))]
+pub use pool::{FormPool, PoolForm};
+pub use printf::PrintfArg;
+pub use radix::{Bin, Hex, HexPad, Oct};
+pub use ring::ArrFormRing;
+pub use segmented::{SegmentedForm, Segments};
+#[cfg(feature = "embedded-hal")]
+pub use serial::FmtSink;
+pub use slice::{format_into, ArrFormRef, FormatTarget};
+pub use split::Remaining;
+pub use storage::Storage;
+pub use stream::StreamForm;
+pub use table::{Column, TableForm};
+pub use tee::Tee;
+pub use time::DateTime;
+pub use url::UrlEncoder;
+pub use width::{char_width, display_width};
+pub use wrap::WordWrap;
 
 #[allow(unused_imports)]
 use core::format_args;
 
+/// Alignment of text inside a field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Position in the content of a buffer, see [ArrForm::checkpoint]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mark {
+    used: usize,
+    dropped: usize,
+}
+
 /// Generates formatted text in a buffer on the stack
 /// 
 /// Allows precise handling of errors. A buffer created once can be used several times. The 
@@ -68,54 +329,1706 @@ use core::format_args;
 /// 
 /// assert_eq!("same buffer, new text, int 123, float 4.1", af.as_str());
 /// ```
-pub struct ArrForm<const BUF_SIZE: usize> {
-    buffer: [u8; BUF_SIZE],
+/// 
+/// The behavior on buffer overflow is selected with the optional policy parameter, see 
+/// [OverflowPolicy]. By default an error is returned.
+pub type ArrForm<const BUF_SIZE: usize, P = ErrorPolicy> = FormBuffer<[u8; BUF_SIZE], P>;
+
+/// Generates formatted text in any backing memory
+/// 
+/// The memory is selected with the [Storage] parameter, so the same formatting code and error 
+/// handling work for owned arrays ([ArrForm]), borrowed slices ([ArrFormRef]) and own buffer 
+/// types.
+/// ```
+/// use arrform::FormBuffer;
+/// 
+/// static mut LOG_BUF: [u8; 256] = [0; 256];
+/// 
+/// // Keep large buffers off the stack
+/// let buf = unsafe { &mut *core::ptr::addr_of_mut!(LOG_BUF) };
+/// let mut af: FormBuffer<_> = FormBuffer::from_storage(buf);
+/// af.format(format_args!("int {}", 42)).unwrap();
+/// assert_eq!("int 42", af.as_str());
+/// ```
+pub struct FormBuffer<S: Storage, P: OverflowPolicy = ErrorPolicy> {
+    buffer: S,
     used: usize,
+    dropped: usize,
+    high_water: usize,
+    limit: usize,
+    // Raw bytes were written, the content must be validated before it is used as str
+    raw: bool,
+    state: P::State,
+    policy: PhantomData<P>,
 }
 
-impl<const BUF_SIZE: usize> ArrForm<BUF_SIZE> {
+impl<const BUF_SIZE: usize, P: OverflowPolicy> FormBuffer<[u8; BUF_SIZE], P> {
 
     /// Creates new buffer on the stack
-    pub fn new() -> Self {
-        // We don't need to initialize, because we write before we read
-        let buffer: [u8; BUF_SIZE] = unsafe { MaybeUninit::uninit().assume_init() };
-        ArrForm { buffer, used: 0 }
+    ///
+    /// The function can be evaluated at compile time, so buffers can be placed in statics.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// static mut PANIC_BUF: ArrForm<64> = ArrForm::new();
+    ///
+    /// let af = unsafe { &mut *core::ptr::addr_of_mut!(PANIC_BUF) };
+    /// af.format(format_args!("panic at line {}", 42)).unwrap();
+    /// assert_eq!("panic at line 42", af.as_str());
+    /// ```
+    pub const fn new() -> Self {
+        // Reading uninitialized integers is undefined behaviour, so the buffer is zeroed
+        FormBuffer {
+            buffer: [0; BUF_SIZE],
+            used: 0,
+            dropped: 0,
+            high_water: 0,
+            limit: BUF_SIZE,
+            raw: false,
+            state: P::STATE,
+            policy: PhantomData,
+        }
+    }
+
+    /// Creates new buffer, which already contains `prefix`
+    ///
+    /// Further text is appended behind the prefix. An error is returned if the prefix does not 
+    /// fit into the buffer. The function can be evaluated at compile time.
+    /// ```
+    /// use core::fmt::Write;
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::with_prefix("[adc] ").unwrap();
+    /// write!(af, "{}", 4711).unwrap();
+    /// assert_eq!("[adc] 4711", af.as_str());
+    ///
+    /// assert!(ArrForm::<4>::with_prefix("[adc] ").is_err());
+    /// ```
+    pub const fn with_prefix(prefix: &str) -> Result<Self, fmt::Error> {
+        let prefix = prefix.as_bytes();
+        if prefix.len() > BUF_SIZE {
+            return Err(fmt::Error);
+        }
+        let mut buffer = [0; BUF_SIZE];
+        let mut i = 0;
+        while i < prefix.len() {
+            // In bounds, the prefix is not longer than the buffer
+            #[allow(clippy::indexing_slicing)]
+            {
+                buffer[i] = prefix[i];
+            }
+            i += 1;
+        }
+        Ok(FormBuffer {
+            buffer,
+            used: prefix.len(),
+            dropped: 0,
+            high_water: 0,
+            limit: BUF_SIZE,
+            raw: false,
+            state: P::STATE,
+            policy: PhantomData,
+        })
+    }
+
+    /// Creates new buffer filled with formatted text
+    ///
+    /// This is the one-liner of [arrform!] with the error handling of 
+    /// [ArrForm::format_detailed], also for sizes given by a const parameter.
+    /// ```
+    /// use arrform::{ArrForm, ArrFormError};
+    ///
+    /// fn label<const N: usize>(id: u32) -> Result<ArrForm<N>, ArrFormError> {
+    ///     ArrForm::format_new(format_args!("id {}", id))
+    /// }
+    ///
+    /// assert_eq!("id 42", label::<8>(42).unwrap().as_str());
+    /// assert_eq!(
+    ///     Err(ArrFormError::Overflow { capacity: 4, needed_at_least: 5 }),
+    ///     label::<4>(42).map(|af| af.len())
+    /// );
+    /// ```
+    pub fn format_new(args: fmt::Arguments) -> Result<Self, ArrFormError> {
+        let mut af = Self::new();
+        af.format_detailed(args)?;
+        Ok(af)
+    }
+
+    /// Creates new buffer, which contains the content of `first` followed by `second`
+    ///
+    /// An error is returned if the joined content does not fit into the buffer.
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let header = arrform!(8, "#{:02}:", 7);
+    /// let body = arrform!(16, "V={}", 3300);
+    /// assert_eq!("#07:V=3300", ArrForm::<16>::concat(&header, &body).unwrap().as_str());
+    /// assert!(ArrForm::<8>::concat(&header, &body).is_err());
+    /// ```
+    pub fn concat<S1, P1, S2, P2>(
+        first: &FormBuffer<S1, P1>,
+        second: &FormBuffer<S2, P2>,
+    ) -> Result<Self, ArrFormError>
+    where
+        S1: Storage,
+        P1: OverflowPolicy,
+        S2: Storage,
+        P2: OverflowPolicy,
+    {
+        let mut af = Self::new();
+        af.try_push_other(first)?.try_push_other(second)?;
+        Ok(af)
+    }
+
+    /// Creates new buffer from existing text given as bytes
+    ///
+    /// An error is returned if the bytes are not valid UTF-8 or do not fit into the buffer.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// assert_eq!("4711", ArrForm::<4>::from_bytes(b"4711").unwrap().as_str());
+    /// assert!(ArrForm::<3>::from_bytes(b"4711").is_err());
+    /// assert!(ArrForm::<4>::from_bytes(&[0x34, 0xff]).is_err());
+    /// ```
+    pub fn from_bytes(b: &[u8]) -> Result<Self, fmt::Error> {
+        let s = from_utf8(b).map_err(|_| fmt::Error)?;
+        Self::with_prefix(s)
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+
+    /// Creates new buffer over the given storage
+    pub fn from_storage(buffer: S) -> Self {
+        let limit = buffer.as_slice().len();
+        FormBuffer {
+            buffer,
+            used: 0,
+            dropped: 0,
+            high_water: 0,
+            limit,
+            raw: false,
+            state: P::STATE,
+            policy: PhantomData,
+        }
+    }
+
+    /// Clear the buffer back to just `prefix`
+    ///
+    /// An error is returned if the prefix does not fit into the buffer.
+    /// ```
+    /// use core::fmt::Write;
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::with_prefix("[adc] ").unwrap();
+    /// for value in [1, 22, 333] {
+    ///     af.reset_to_prefix("[adc] ").unwrap();
+    ///     write!(af, "{}", value).unwrap();
+    /// }
+    /// assert_eq!("[adc] 333", af.as_str());
+    /// ```
+    pub fn reset_to_prefix(&mut self, prefix: &str) -> fmt::Result {
+        if prefix.len() > self.capacity() {
+            return Err(fmt::Error);
+        }
+        self.note_high_water();
+        self.used = 0;
+        self.dropped = 0;
+        self.raw = false;
+        self.write_text(prefix)
     }
 
     /// Format numbers and strings
+    ///
+    /// If the text does not fit, the buffer keeps the text up to the last complete character, 
+    /// so the clipped content is still valid UTF-8.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<5>::new();
+    /// assert!(af.format(format_args!("{}µF", 4711)).is_err());
+    /// assert_eq!("4711", af.as_str());
+    /// ```
     pub fn format(&mut self, args: fmt::Arguments) -> fmt::Result {
+        self.note_high_water();
         self.used = 0;                  // if format is used several times
-        fmt::write(self, args)
+        self.dropped = 0;
+        self.raw = false;
+        self.append(args)
+    }
+
+    /// Remove the content, so the buffer can be reused
+    pub fn clear(&mut self) {
+        self.note_high_water();
+        self.used = 0;
+        self.dropped = 0;
+        self.raw = false;
+    }
+
+    /// Shorten the content to at most `len` bytes
+    ///
+    /// If `len` is not on a character boundary, the content is cut before the character. This 
+    /// also allows rewinding to a length recorded earlier with [ArrForm::len].
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let mut af = arrform!(32, "AT+CWJAP=");
+    /// let mark = af.len();
+    /// af.append(format_args!("\"{}\"", "ssid")).unwrap();
+    /// af.truncate(mark);
+    /// assert_eq!("AT+CWJAP=", af.as_str());
+    ///
+    /// let mut af = arrform!(32, "21°C");
+    /// af.truncate(3);
+    /// assert_eq!("21", af.as_str());
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.used {
+            return;
+        }
+        let s = self.as_str();
+        let mut len = len;
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.note_high_water();
+        self.used = len;
+        self.dropped = 0;
+    }
+
+    /// Insert a string at byte position `idx`, moving the following text to the right
+    ///
+    /// An error is returned and the content is not changed if `idx` is not on a character 
+    /// boundary or the text does not fit into the buffer.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let mut af = arrform!(16, "RPM: 34");
+    /// af.insert_str(5, "12").unwrap();
+    /// assert_eq!("RPM: 1234", af.as_str());
+    /// assert!(af.insert_str(0, "engine 1 ").is_err());
+    /// ```
+    pub fn insert_str(&mut self, idx: usize, s: &str) -> fmt::Result {
+        self.replace_range(idx..idx, s)
+    }
+
+    /// Replace the text in the byte `range` by a string of any length
+    ///
+    /// This allows patching a single field of a cached line instead of formatting the whole 
+    /// line again. An error is returned and the content is not changed if the range is not 
+    /// inside the content, does not lie on character boundaries or the result does not fit 
+    /// into the buffer.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let mut af = arrform!(24, "RPM: 1234  TEMP: 56");
+    /// af.replace_range(5..9, &arrform!(4, "{:4}", 987)).unwrap();
+    /// assert_eq!("RPM:  987  TEMP: 56", af.as_str());
+    /// af.replace_range(17.., "102").unwrap();
+    /// assert_eq!("RPM:  987  TEMP: 102", af.as_str());
+    /// assert!(af.replace_range(17..30, "0").is_err());
+    /// ```
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, s: &str) -> fmt::Result {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).ok_or(fmt::Error)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).ok_or(fmt::Error)?,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.used,
+        };
+        let content = self.as_str();
+        if start > end || !content.is_char_boundary(start) || !content.is_char_boundary(end) {
+            return Err(fmt::Error);
+        }
+        let used = (self.used - (end - start)).checked_add(s.len()).ok_or(fmt::Error)?;
+        if used > self.limit {
+            return Err(fmt::Error);
+        }
+
+        // Move the tail first, then copy the new text into the gap. In bounds, the range lies 
+        // inside the content and the result fits into the buffer.
+        let gap_end = start + s.len();
+        let buffer = self.buffer.as_mut_slice();
+        buffer.copy_within(end..self.used, gap_end);
+        buffer.get_mut(start..gap_end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.note_high_water();
+        self.used = used;
+        self.dropped = 0;
+        Ok(())
+    }
+
+    /// Remember the current end of the content
+    ///
+    /// Use [ArrForm::rollback] to remove everything written after the checkpoint, e.g. an 
+    /// optional section of a message, which turned out to be invalid.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.format(format_args!("T={}", 21)).unwrap();
+    /// let mark = af.checkpoint();
+    /// af.append(format_args!(";GPS={:.4}", 48.1372)).unwrap();
+    /// let gps_valid = false;
+    /// if !gps_valid {
+    ///     af.rollback(mark);
+    /// }
+    /// af.append(format_args!(";H={}", 45)).unwrap();
+    /// assert_eq!("T=21;H=45", af.as_str());
+    /// ```
+    pub fn checkpoint(&self) -> Mark {
+        Mark { used: self.used, dropped: self.dropped }
+    }
+
+    /// Remove everything written after `mark`, including an overflow
+    ///
+    /// Nothing happens if the content is already shorter than at the checkpoint or the mark is 
+    /// not on a character boundary, e.g. because the buffer was cleared in between.
+    pub fn rollback(&mut self, mark: Mark) {
+        if self.as_str().is_char_boundary(mark.used) {
+            self.note_high_water();
+            self.used = mark.used;
+            self.dropped = mark.dropped;
+        }
+    }
+
+    /// Format numbers and strings, appending to the existing content
+    ///
+    /// In contrast to [ArrForm::format], the buffer is not reset. This allows composing a 
+    /// message in several steps.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.format(format_args!("T={:.1}", 21.56)).unwrap();
+    /// af.append(format_args!(" H={}%", 45)).unwrap();
+    /// assert_eq!("T=21.6 H=45%", af.as_str());
+    /// ```
+    pub fn append(&mut self, args: fmt::Arguments) -> fmt::Result {
+        // Plain literals are copied without the formatting machinery
+        match args.as_str() {
+            Some(s) => fmt::Write::write_str(self, s),
+            None => fmt::write(self, args),
+        }
+    }
+
+    /// Format numbers and strings with detailed error information
+    ///
+    /// On success the length of the text is returned. An overflow is reported even if the 
+    /// [OverflowPolicy] saturates, so the caller can decide to retry with a bigger buffer.
+    /// ```
+    /// use arrform::{ArrForm, ArrFormError};
+    ///
+    /// let mut af = ArrForm::<8>::new();
+    /// assert_eq!(Ok(6), af.format_detailed(format_args!("int {}", 42)));
+    /// assert_eq!(
+    ///     Err(ArrFormError::Overflow { capacity: 8, needed_at_least: 9 }),
+    ///     af.format_detailed(format_args!("int {}", 47110))
+    /// );
+    /// ```
+    pub fn format_detailed(&mut self, args: fmt::Arguments) -> Result<usize, ArrFormError> {
+        let result = self.format(args);
+        if self.truncated() {
+            Err(self.overflow_error())
+        } else {
+            result.map(|_| self.used).map_err(|_| ArrFormError::Fmt)
+        }
+    }
+
+    /// Format numbers and strings, keeping the previous content if the text does not fit
+    ///
+    /// The length of the text is measured first with a [CountingForm], so the arguments are 
+    /// formatted twice, but no second buffer is needed. On error the buffer is not changed, so 
+    /// a display keeps showing the last valid frame. On success the length of the text is 
+    /// returned.
+    /// ```
+    /// use arrform::{ArrForm, ArrFormError};
+    ///
+    /// let mut af = ArrForm::<8>::new();
+    /// assert_eq!(Ok(6), af.format_atomic(format_args!("T={:.1}", 21.56)));
+    /// assert_eq!(
+    ///     Err(ArrFormError::Overflow { capacity: 8, needed_at_least: 9 }),
+    ///     af.format_atomic(format_args!("T={:.4}", 21.56))
+    /// );
+    /// assert_eq!("T=21.6", af.as_str());
+    /// ```
+    pub fn format_atomic(&mut self, args: fmt::Arguments) -> Result<usize, ArrFormError> {
+        let mut counter = CountingForm::new();
+        fmt::write(&mut counter, args).map_err(|_| ArrFormError::Fmt)?;
+        if counter.len() > self.limit {
+            return Err(ArrFormError::Overflow {
+                capacity: self.capacity(),
+                needed_at_least: counter.len(),
+            });
+        }
+        self.format_detailed(args)
+    }
+
+    /// Format numbers and strings, using at most `limit` bytes of the buffer
+    ///
+    /// The text overflows once it reaches `limit`, so the tail of the buffer stays free for 
+    /// framing or checksum bytes, which can be appended afterwards. A `limit` greater than the 
+    /// buffer size behaves like [ArrForm::format].
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<8>::new();
+    /// assert!(af.format_limited(6, format_args!("{}", 1234567)).is_err());
+    /// af.push_str("\r\n").unwrap();
+    /// assert_eq!("123456\r\n", af.as_str());
+    /// ```
+    pub fn format_limited(&mut self, limit: usize, args: fmt::Arguments) -> fmt::Result {
+        self.limit = limit.min(self.capacity());
+        let result = self.format(args);
+        self.limit = self.capacity();
+        result
+    }
+
+    /// Format numbers and strings, keeping as much of the text as fits
+    ///
+    /// This function never returns an error. Use [ArrForm::truncated] to find out whether the 
+    /// text was clipped.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<8>::new();
+    /// af.format_lossy(format_args!("T={:.1} C", 21.456));
+    /// assert_eq!("T=21.5 C", af.as_str());
+    /// assert!(!af.truncated());
+    ///
+    /// af.format_lossy(format_args!("T={:.2} C", 21.456));
+    /// assert_eq!("T=21.46 ", af.as_str());
+    /// assert!(af.truncated());
+    /// ```
+    pub fn format_lossy(&mut self, args: fmt::Arguments) {
+        let _ = self.format(args);
+    }
+
+    /// Format numbers and strings as null-terminated C string
+    ///
+    /// The last byte of the buffer is reserved for the terminating `\0`, so the text overflows 
+    /// one byte earlier than with [ArrForm::format]. An error is returned on overflow or if the 
+    /// text contains a `\0`.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<8>::new();
+    /// let cstr = af.format_cstr(format_args!("ch {}", 11)).unwrap();
+    /// assert_eq!(b"ch 11\0", cstr.to_bytes_with_nul());
+    ///
+    /// assert!(af.format_cstr(format_args!("ch {}", 11111)).is_err());
+    /// ```
+    pub fn format_cstr(&mut self, args: fmt::Arguments) -> Result<&CStr, fmt::Error> {
+        let limit = self.capacity().saturating_sub(1);
+        self.format_limited(limit, args)?;
+        self.as_cstr()
+    }
+
+    /// Format numbers and strings, marking a truncated result with an ellipsis
+    ///
+    /// This function never fails. If the text does not fit into the buffer, the end of the 
+    /// buffer is overwritten with `"..."`. The result always stays valid UTF-8 and 
+    /// [ArrForm::truncated] reports `true`.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<12>::new();
+    /// af.format_with_ellipsis(format_args!("temperature {}°C", 21));
+    /// assert_eq!("temperatu...", af.as_str());
+    /// assert!(af.truncated());
+    ///
+    /// af.format_with_ellipsis(format_args!("{}°C", 21));
+    /// assert_eq!("21°C", af.as_str());
+    /// assert!(!af.truncated());
+    /// ```
+    pub fn format_with_ellipsis(&mut self, args: fmt::Arguments) {
+        self.format_with_marker("...", args);
+    }
+
+    /// Format numbers and strings, marking a truncated result with `marker`
+    ///
+    /// Works like [ArrForm::format_with_ellipsis], but the end of a clipped text is overwritten 
+    /// with the given marker, e.g. `"…"`. A marker longer than the buffer is cut.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<10>::new();
+    /// af.format_with_marker("…", format_args!("RPM {:.2}", 1234.567));
+    /// assert_eq!("RPM 123…", af.as_str());
+    /// assert!(af.truncated());
+    ///
+    /// af.format_with_marker("..", format_args!("RPM {}", 1234));
+    /// assert_eq!("RPM 1234", af.as_str());
+    /// ```
+    pub fn format_with_marker(&mut self, marker: &str, args: fmt::Arguments) {
+        self.format_lossy(args);
+        if !self.truncated() {
+            return;
+        }
+
+        let capacity = self.capacity();
+        let mut len = marker.len().min(capacity);
+        while !marker.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        // Never leave a partial character in front of the marker
+        let mut start = self.used.min(capacity - len);
+        while !self.as_str().is_char_boundary(start) {
+            start -= 1;
+        }
+        self.note_high_water();
+        self.used = start;
+        // Cannot overflow, the marker fits behind `start`
+        let _ = self.push_bytes(marker.as_bytes().get(..len).unwrap_or_default());
+    }
+
+    /// Returns `true` if the content was cut, because the buffer was too small
+    pub fn truncated(&self) -> bool {
+        self.dropped > 0
+    }
+
+    /// Append raw bytes to the buffer
+    ///
+    /// The bytes are copied without any UTF-8 assumption, so the buffer can be used to assemble 
+    /// binary frames. Overflow is handled like in the formatting functions: as many bytes as fit 
+    /// are copied and the [OverflowPolicy] decides about the result. After writing binary 
+    /// content use [ArrForm::as_bytes] or [ArrForm::as_str_checked] to read the result. Until 
+    /// the buffer is cleared, [ArrForm::as_str] validates the content and returns only the 
+    /// valid text in front.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<4>::new();
+    /// af.write_bytes(&[0x02, 0xff]).unwrap();
+    /// assert!(af.write_bytes(&[0x10, 0x20, 0x03]).is_err());
+    /// assert_eq!(&[0x02, 0xff, 0x10, 0x20], af.as_bytes());
+    /// assert_eq!("\x02", af.as_str());
+    /// ```
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.raw = true;
+        self.write_ascii(bytes)
+    }
+
+    /// Append bytes, which are ASCII, so the content stays valid UTF-8
+    fn write_ascii(&mut self, bytes: &[u8]) -> fmt::Result {
+        let fit = bytes.len().min(self.limit - self.used);
+        self.write_prefix(bytes, fit)
+    }
+
+    /// Append bytes, which are validated to be UTF-8 text
+    ///
+    /// Nothing is written and an error is returned if the bytes are not valid UTF-8. On 
+    /// overflow the text is cut at the last complete character, so [ArrForm::as_str] stays 
+    /// usable.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.write_utf8(b"OK ").unwrap();
+    /// assert!(af.write_utf8(&[0x34, 0xff]).is_err());
+    /// assert_eq!("OK ", af.as_str());
+    /// ```
+    pub fn write_utf8(&mut self, bytes: &[u8]) -> fmt::Result {
+        let s = from_utf8(bytes).map_err(|_| fmt::Error)?;
+        self.write_text(s)
+    }
+
+    /// Append bytes, which are known to be UTF-8 text, without validation
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8, e.g. pre-validated ASCII fragments, otherwise the 
+    /// behaviour is undefined. Bytes of unknown content are appended with [ArrForm::write_utf8] 
+    /// or, if they are no text, with [ArrForm::write_bytes]. Debug builds check the contract.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// unsafe { af.write_utf8_unchecked(b"RSSI -67") }.unwrap();
+    /// assert_eq!("RSSI -67", af.as_str());
+    /// ```
+    #[cfg(not(feature = "safe"))]
+    pub unsafe fn write_utf8_unchecked(&mut self, bytes: &[u8]) -> fmt::Result {
+        debug_assert!(from_utf8(bytes).is_ok(), "bytes are not valid UTF-8");
+        self.write_text(core::str::from_utf8_unchecked(bytes))
+    }
+
+    /// Append a string, cutting it at the last complete character on overflow
+    fn write_text(&mut self, s: &str) -> fmt::Result {
+        let mut fit = s.len().min(self.limit - self.used);
+        while !s.is_char_boundary(fit) {
+            fit -= 1;
+        }
+        self.write_prefix(s.as_bytes(), fit)
+    }
+
+    /// Copy bytes behind the content, the caller made sure that they fit
+    #[inline]
+    fn push_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        let start = self.used;
+        let end = start + bytes.len();
+        let dst = self.buffer.as_mut_slice().get_mut(start..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(bytes);
+        self.used = end;
+        Ok(())
+    }
+
+    /// The written part of the buffer
+    fn filled(&self) -> &[u8] {
+        self.buffer.as_slice().get(..self.used).unwrap_or_default()
+    }
+
+    fn filled_mut(&mut self) -> &mut [u8] {
+        let used = self.used;
+        self.buffer.as_mut_slice().get_mut(..used).unwrap_or_default()
+    }
+
+    /// Copy the first `fit` bytes, an overflow occurs if not all bytes fit
+    fn write_prefix(&mut self, bytes: &[u8], fit: usize) -> fmt::Result {
+        // Buffer is already full, nothing to copy
+        if fit == 0 && !bytes.is_empty() {
+            self.dropped = self.dropped.saturating_add(bytes.len());
+            return self.on_overflow();
+        }
+
+        self.push_bytes(bytes.get(..fit).unwrap_or_default())?;
+
+        // Treat buffer overflow
+        if fit < bytes.len() {
+            self.dropped = self.dropped.saturating_add(bytes.len() - fit);
+            self.on_overflow()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Append a string without the formatting machinery
+    ///
+    /// Returns the buffer again, so several calls can be chained.
+    /// ```
+    /// use arrform::{ArrForm, ArrFormError};
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.push_str("AT")?.push('+')?.push_str("RST")?;
+    /// assert_eq!("AT+RST", af.as_str());
+    /// # Ok::<(), ArrFormError>(())
+    /// ```
+    pub fn push_str(&mut self, s: &str) -> Result<&mut Self, ArrFormError> {
+        match self.write_text(s) {
+            Ok(()) => Ok(self),
+            Err(_) => Err(self.overflow_error()),
+        }
+    }
+
+    /// Append a single character without the formatting machinery
+    pub fn push(&mut self, c: char) -> Result<&mut Self, ArrFormError> {
+        let mut encoded = [0u8; 4];
+        self.push_str(c.encode_utf8(&mut encoded))
+    }
+
+    /// Append a character `count` times, e.g. for horizontal rules or bar graphs
+    ///
+    /// The characters are copied in one go instead of passing each through the write path.
+    /// ```
+    /// use arrform::{ArrForm, ArrFormError};
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.push('[')?.push_repeat('#', 6)?.push_repeat('.', 4)?.push(']')?;
+    /// assert_eq!("[######....]", af.as_str());
+    /// assert!(af.push_repeat('-', 8).is_err());
+    /// assert_eq!("[######....]----", af.as_str());
+    /// # Ok::<(), ArrFormError>(())
+    /// ```
+    pub fn push_repeat(&mut self, c: char, count: usize) -> Result<&mut Self, ArrFormError> {
+        let mut encoded = [0u8; 4];
+        self.push_sep(c.encode_utf8(&mut encoded), count)
+    }
+
+    /// Append a string `n` times, e.g. a multi-character separator
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.push_sep("=-", 5).unwrap();
+    /// assert_eq!("=-=-=-=-=-", af.as_str());
+    /// ```
+    pub fn push_sep(&mut self, s: &str, n: usize) -> Result<&mut Self, ArrFormError> {
+        match self.write_repeated(s, n) {
+            Ok(()) => Ok(self),
+            Err(_) => Err(self.overflow_error()),
+        }
+    }
+
+    /// Copy as many complete repetitions of `s` as fit, then treat the rest like `write_text`
+    fn write_repeated(&mut self, s: &str, n: usize) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        let whole = n.min((self.limit - self.used) / s.len());
+        let start = self.used;
+        let end = start + whole * s.len();
+        let dst = self.buffer.as_mut_slice().get_mut(start..end).ok_or(fmt::Error)?;
+        for chunk in dst.chunks_exact_mut(s.len()) {
+            chunk.copy_from_slice(s.as_bytes());
+        }
+        self.used = end;
+
+        if whole < n {
+            self.dropped = self.dropped.saturating_add((n - whole - 1).saturating_mul(s.len()));
+            return self.write_text(s);
+        }
+        Ok(())
+    }
+
+    /// Append the content of another buffer
+    ///
+    /// In contrast to [ArrForm::push_str] the content is appended completely or not at all, so 
+    /// independently prepared fragments can be joined safely. The content is copied as bytes, 
+    /// so binary frames can be joined as well.
+    /// ```
+    /// use arrform::{arrform, ArrForm, ArrFormError};
+    ///
+    /// let header = arrform!(8, "#{:02}:", 7);
+    /// let body = arrform!(16, "V={}", 3300);
+    ///
+    /// let mut frame = ArrForm::<12>::new();
+    /// frame.try_push_other(&header)?.try_push_other(&body)?;
+    /// assert_eq!("#07:V=3300", frame.as_str());
+    /// assert_eq!(
+    ///     Err(ArrFormError::Overflow { capacity: 12, needed_at_least: 14 }),
+    ///     frame.try_push_other(&header).map(|af| af.len())
+    /// );
+    /// assert_eq!("#07:V=3300", frame.as_str());
+    /// # Ok::<(), ArrFormError>(())
+    /// ```
+    pub fn try_push_other<S2: Storage, P2: OverflowPolicy>(
+        &mut self,
+        other: &FormBuffer<S2, P2>,
+    ) -> Result<&mut Self, ArrFormError> {
+        let bytes = other.as_bytes();
+        if bytes.len() > self.limit - self.used {
+            return Err(ArrFormError::Overflow {
+                capacity: self.capacity(),
+                needed_at_least: self.used + bytes.len(),
+            });
+        }
+        self.push_bytes(bytes).map_err(|_| ArrFormError::Fmt)?;
+        self.raw |= other.raw;
+        Ok(self)
+    }
+
+    /// Apply the [OverflowPolicy] of the buffer
+    fn on_overflow(&self) -> fmt::Result {
+        P::on_overflow_in(self.state)
+    }
+
+    fn overflow_error(&self) -> ArrFormError {
+        ArrFormError::Overflow {
+            capacity: self.capacity(),
+            needed_at_least: self.used + self.dropped,
+        }
+    }
+
+    /// Append several string fragments without the formatting machinery
+    ///
+    /// The fragments are written in order. Writing stops at the first fragment, which does not 
+    /// fit. Fragments written before stay in the buffer.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<10>::new();
+    /// af.extend_str(&["AT", "+", "CWJAP"]).unwrap();
+    /// assert_eq!("AT+CWJAP", af.as_str());
+    ///
+    /// assert!(af.extend_str(&["=", "\"ssid\""]).is_err());
+    /// assert_eq!("AT+CWJAP=\"", af.as_str());
+    /// ```
+    pub fn extend_str(&mut self, parts: &[&str]) -> fmt::Result {
+        for part in parts {
+            self.write_text(part)?;
+        }
+        Ok(())
+    }
+
+    /// Append an unsigned number in decimal notation, bypassing `core::fmt`
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// for v in [0, 9, 10, 4711, u32::MAX] {
+    ///     af.format(format_args!("")).unwrap();
+    ///     af.put_u32(v).unwrap();
+    ///     assert_eq!(arrform!(32, "{}", v).as_str(), af.as_str());
+    /// }
+    /// ```
+    pub fn put_u32(&mut self, v: u32) -> fmt::Result {
+        self.put_decimal(v, false)
+    }
+
+    /// Append a signed number in decimal notation, bypassing `core::fmt`
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// for v in [i32::MIN, -4711, -1, 0, 1, i32::MAX] {
+    ///     af.format(format_args!("")).unwrap();
+    ///     af.put_i32(v).unwrap();
+    ///     assert_eq!(arrform!(32, "{}", v).as_str(), af.as_str());
+    /// }
+    /// ```
+    pub fn put_i32(&mut self, v: i32) -> fmt::Result {
+        self.put_decimal(v.unsigned_abs(), v < 0)
+    }
+
+    /// Append a byte value in decimal notation, bypassing `core::fmt`
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// for v in [0, 42, u8::MAX] {
+    ///     af.format(format_args!("")).unwrap();
+    ///     af.put_u8(v).unwrap();
+    ///     assert_eq!(arrform!(32, "{}", v).as_str(), af.as_str());
+    /// }
+    /// ```
+    pub fn put_u8(&mut self, v: u8) -> fmt::Result {
+        self.put_decimal(v as u32, false)
+    }
+
+    /// Append a scaled integer as decimal number with `scale` decimals
+    ///
+    /// Readings like millivolts can be printed without converting them to a float first.
+    /// ```
+    /// use arrform::{ArrForm, SaturatePolicy};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_fixed(3300, 3).unwrap();
+    /// assert_eq!("3.300", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_fixed(-5, 2).unwrap();
+    /// assert_eq!("-0.05", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_fixed(123, 0).unwrap();
+    /// assert_eq!("123", af.as_str());
+    ///
+    /// let mut af = ArrForm::<8, SaturatePolicy>::new();
+    /// af.write_fixed(5, u32::MAX).unwrap();
+    /// assert_eq!("0.000000", af.as_str());
+    /// ```
+    pub fn write_fixed(&mut self, value: i32, scale: u32) -> fmt::Result {
+        self.write_fixed_sep(value, scale, '.')
+    }
+
+    /// Append a scaled integer like [ArrForm::write_fixed] with another decimal separator
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_fixed_sep(2150, 2, ',').unwrap();
+    /// assert_eq!("21,50", af.as_str());
+    /// ```
+    pub fn write_fixed_sep(&mut self, value: i32, scale: u32, separator: char) -> fmt::Result {
+        let v = value.unsigned_abs();
+        let (int, frac) = match 10u32.checked_pow(scale) {
+            Some(divisor) => (v / divisor, v % divisor),
+            None => (0, v),
+        };
+        self.put_decimal(int, value < 0)?;
+        if scale == 0 {
+            return Ok(());
+        }
+        self.write_decimal_separator(separator)?;
+
+        self.write_zero_padded(frac, scale)
+    }
+
+    fn write_decimal_separator(&mut self, separator: char) -> fmt::Result {
+        let mut encoded = [0u8; 4];
+        self.write_text(separator.encode_utf8(&mut encoded))
+    }
+
+    fn write_zero_padded(&mut self, v: u32, width: u32) -> fmt::Result {
+        let len = v.checked_ilog10().map_or(1, |log| log + 1);
+        self.write_repeated("0", width.saturating_sub(len) as usize)?;
+        self.put_decimal(v, false)
+    }
+
+    fn put_decimal(&mut self, mut v: u32, negative: bool) -> fmt::Result {
+        // Sign and 10 digits of u32::MAX, filled from the end
+        let mut digits = [0u8; 11];
+        let mut start = digits.len();
+        for digit in digits.iter_mut().rev() {
+            start -= 1;
+            *digit = b'0' + (v % 10) as u8;
+            v /= 10;
+            if v == 0 {
+                break;
+            }
+        }
+        if negative {
+            start -= 1;
+            if let Some(sign) = digits.get_mut(start) {
+                *sign = b'-';
+            }
+        }
+        self.write_ascii(digits.get(start..).unwrap_or_default())
+    }
+
+    /// Append `fill` characters until the content is `width` bytes long
+    ///
+    /// Only whole characters are appended, so with a multi-byte `fill` the content may stay
+    /// shorter than `width`. Nothing is appended if the content is already long enough. The
+    /// width counts from the start of the content, not of the current field: for a later
+    /// column, pass the position where the column ends.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.extend_str(&["temp"]).unwrap();
+    /// af.pad_to(8, '.').unwrap();
+    /// af.put_u32(21).unwrap();
+    /// assert_eq!("temp....21", af.as_str());
+    ///
+    /// af.clear();
+    /// af.push_str("abc").unwrap();
+    /// af.pad_to(6, '·').unwrap();
+    /// assert_eq!("abc·", af.as_str());
+    /// ```
+    pub fn pad_to(&mut self, width: usize, fill: char) -> fmt::Result {
+        let mut encoded = [0u8; 4];
+        let fill: &str = fill.encode_utf8(&mut encoded);
+        let count = width.saturating_sub(self.used) / fill.len();
+        self.write_repeated(fill, count)
+    }
+
+    /// Format numbers and strings right-aligned to the end of the buffer
+    ///
+    /// The buffer is filled completely, the text is preceded by the ASCII byte `fill`. On 
+    /// success the offset of the text is returned. This suits seven-segment and other 
+    /// fixed-position displays.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut display = ArrForm::<6>::new();
+    /// assert_eq!(Ok(2), display.format_right(b' ', format_args!("{:.1}", -3.14)));
+    /// assert_eq!("  -3.1", display.as_str());
+    /// ```
+    pub fn format_right(&mut self, fill: u8, args: fmt::Arguments) -> Result<usize, fmt::Error> {
+        if !fill.is_ascii() {
+            return Err(fmt::Error);
+        }
+        self.clear();
+        let result = self.append(args);
+        let offset = self.align_right(fill);
+        result.map(|_| offset)
+    }
+
+    /// Write an unsigned number right-aligned to the end of the buffer, see 
+    /// [ArrForm::format_right]
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut display = ArrForm::<4>::new();
+    /// assert_eq!(Ok(1), display.put_u32_right(b'0', 815));
+    /// assert_eq!("0815", display.as_str());
+    /// ```
+    pub fn put_u32_right(&mut self, fill: u8, v: u32) -> Result<usize, fmt::Error> {
+        if !fill.is_ascii() {
+            return Err(fmt::Error);
+        }
+        self.clear();
+        let result = self.put_u32(v);
+        let offset = self.align_right(fill);
+        result.map(|_| offset)
+    }
+
+    /// Move the content to the end of the buffer and fill the space before
+    fn align_right(&mut self, fill: u8) -> usize {
+        let len = self.used;
+        let offset = self.limit - len;
+        let buffer = self.buffer.as_mut_slice();
+        buffer.copy_within(0..len, offset);
+        if let Some(pad) = buffer.get_mut(..offset) {
+            pad.fill(fill);
+        }
+        self.used = self.limit;
+        offset
+    }
+
+    /// Fill the rest of the buffer with `fill` characters
+    ///
+    /// This expands a message to the fixed frame size of a DMA or packet engine. A multi-byte 
+    /// `fill` character is repeated as long as it fits completely.
+    /// ```
+    /// use arrform::{ArrForm, SaturatePolicy};
+    ///
+    /// let mut frame = ArrForm::<12>::new();
+    /// frame.format(format_args!("ID{}", 7)).unwrap();
+    /// frame.fill_remaining('\0');
+    /// assert_eq!(b"ID7\0\0\0\0\0\0\0\0\0", frame.as_bytes());
+    ///
+    /// // Padding stops, when the next fill character does not fit completely
+    /// let mut af = ArrForm::<6, SaturatePolicy>::new();
+    /// af.format(format_args!("abc")).unwrap();
+    /// af.pad_to(10, '·').unwrap();
+    /// assert_eq!("abc·", af.as_str());
+    /// af.fill_remaining('·');
+    /// assert_eq!("abc·", af.as_str());
+    /// ```
+    pub fn fill_remaining(&mut self, fill: char) {
+        let mut encoded = [0u8; 4];
+        let fill: &str = fill.encode_utf8(&mut encoded);
+        while self.limit - self.used >= fill.len() {
+            if self.push_bytes(fill.as_bytes()).is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Append a string, padded with spaces to `width` characters
+    ///
+    /// Right alignment pads before writing the string, left alignment pads after. Strings 
+    /// longer than `width` are written completely.
+    /// ```
+    /// use arrform::{Align, ArrForm};
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.write_padded("a", 3, Align::Left).unwrap();
+    /// af.write_padded("°C", 4, Align::Right).unwrap();
+    /// assert_eq!("a    °C", af.as_str());
+    /// ```
+    pub fn write_padded(&mut self, s: &str, width: usize, align: Align) -> fmt::Result {
+        let padding = width.saturating_sub(s.chars().count());
+        match align {
+            Align::Left => {
+                self.write_text(s)?;
+                self.write_spaces(padding)
+            }
+            Align::Right => {
+                self.write_spaces(padding)?;
+                self.write_text(s)
+            }
+        }
+    }
+
+    /// Format numbers and strings into a field of exactly `width` bytes
+    ///
+    /// Shorter text is padded with the ASCII byte `fill`, according to `align`. Text longer 
+    /// than `width` is clipped to the field and an overflow is reported. Nothing is written and 
+    /// an error is returned if `fill` is not ASCII.
+    /// ```
+    /// use arrform::{Align, ArrForm};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_field(6, Align::Right, b'0', format_args!("{}", 4711)).unwrap();
+    /// af.write_field(5, Align::Left, b' ', format_args!("{}", "ok")).unwrap();
+    /// af.push_str("|").unwrap();
+    /// assert_eq!("004711ok   |", af.as_str());
+    ///
+    /// af.clear();
+    /// assert!(af.write_field(3, Align::Left, b' ', format_args!("{}", 4711)).is_err());
+    /// assert_eq!("471", af.as_str());
+    /// ```
+    pub fn write_field(
+        &mut self,
+        width: usize,
+        align: Align,
+        fill: u8,
+        args: fmt::Arguments,
+    ) -> fmt::Result {
+        let clipped = self.put_field(width, align, fill, args)?;
+        if clipped > 0 {
+            self.dropped += clipped;
+            return self.on_overflow();
+        }
+        Ok(())
+    }
+
+    /// Write a field like [ArrForm::write_field] and get the number of bytes clipped
+    ///
+    /// Clipping is not an overflow here, only a field reaching beyond the buffer is.
+    pub(crate) fn put_field(
+        &mut self,
+        width: usize,
+        align: Align,
+        fill: u8,
+        args: fmt::Arguments,
+    ) -> Result<usize, fmt::Error> {
+        if !fill.is_ascii() {
+            return Err(fmt::Error);
+        }
+        let start = self.used;
+        let field = width.min(self.limit - start);
+
+        // Format into the field only, longer text is cut after the last complete character
+        let area = self.buffer.as_mut_slice().get_mut(start..start + field).ok_or(fmt::Error)?;
+        let mut text = ArrFormRef::<SaturatePolicy>::from_storage(area);
+        let result = text.append(args);
+        let (len, clipped) = (text.used, text.dropped);
+
+        let buffer = self.buffer.as_mut_slice();
+        let pad = if align == Align::Right {
+            buffer.copy_within(start..start + len, start + field - len);
+            buffer.get_mut(start..start + field - len)
+        } else {
+            buffer.get_mut(start + len..start + field)
+        };
+        if let Some(pad) = pad {
+            pad.fill(fill);
+        }
+        self.used = start + field;
+
+        if field < width {
+            self.dropped += width - field;
+            self.on_overflow()?;
+        }
+        result.map(|()| clipped)
+    }
+
+    fn write_spaces(&mut self, count: usize) -> fmt::Result {
+        self.write_repeated(" ", count)
+    }
+
+    /// Get the size of the buffer in bytes
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(16, "AT+{}", "RST");
+    /// assert_eq!(16, af.capacity());
+    /// assert_eq!(6, af.len());
+    /// assert_eq!(10, af.remaining());
+    /// assert!(!af.is_empty());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.buffer.as_slice().len()
+    }
+
+    /// Get the length of the content in bytes
+    pub fn len(&self) -> usize {
+        self.used
+    }
+
+    /// Get the number of bytes, which are still free
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.used
+    }
+
+    /// Returns `true` if the buffer has no content
+    pub fn is_empty(&self) -> bool {
+        self.used == 0
+    }
+
+    /// Get the largest length of the content since the buffer was created
+    ///
+    /// The mark is kept when the buffer is reused, so a test run shows how much of the 
+    /// capacity is really needed. After an overflow the mark equals the capacity.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// for v in [7, 4711, 42] {
+    ///     af.format(format_args!("v={}", v)).unwrap();
+    /// }
+    /// assert_eq!("v=42", af.as_str());
+    /// assert_eq!(6, af.high_water_mark());
+    ///
+    /// af.reset_high_water_mark();
+    /// assert_eq!(4, af.high_water_mark());
+    /// ```
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water.max(self.used)
+    }
+
+    /// Restart the tracking of [ArrForm::high_water_mark] at the current length
+    pub fn reset_high_water_mark(&mut self) {
+        self.high_water = 0;
+    }
+
+    /// Remember the length of the content before it is reduced
+    fn note_high_water(&mut self) {
+        self.high_water = self.high_water.max(self.used);
+    }
+
+    /// Check whether `additional` bytes still fit into the buffer
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<8>::new();
+    /// af.format(format_args!("{}", 1234)).unwrap();
+    /// assert!(af.has_room_for(4));
+    /// assert!(!af.has_room_for(5));
+    /// ```
+    pub fn has_room_for(&self, additional: usize) -> bool {
+        additional <= self.remaining()
+    }
+
+    /// Like [ArrForm::has_room_for], but returns an error if the bytes do not fit
+    pub fn try_reserve(&self, additional: usize) -> fmt::Result {
+        if self.has_room_for(additional) {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
     }
 
     /// Get a reference to the result as a slice inside the buffer as str
+    ///
+    /// If raw bytes were written with [ArrForm::write_bytes], only the valid text in front is
+    /// returned. Use [ArrForm::as_str_checked] to detect invalid content.
     pub fn as_str(&self) -> &str {
+        if self.raw || !S::is_trusted(Token) {
+            return valid_prefix(self.filled());
+        }
         // We are really sure, that the buffer contains only valid utf8 characters
-        unsafe { from_utf8_unchecked(&self.buffer[..self.used]) }
+        from_utf8_trusted(self.filled())
+    }
+
+    /// Get a reference to the result as str, after validating the content
+    pub fn as_str_checked(&self) -> Result<&str, Utf8Error> {
+        from_utf8(self.filled())
+    }
+
+    /// Get the longest prefix of the content, which is at most `max_bytes` long
+    ///
+    /// The prefix always ends on a character boundary. Its length is the number of bytes 
+    /// consumed, see [ArrForm::take_prefix_from] to continue with the rest.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(16, "{}°C", 21);
+    /// assert_eq!("21", af.take_prefix(3));
+    /// assert_eq!("21°", af.take_prefix(4));
+    /// assert_eq!("21°C", af.take_prefix(64));
+    /// assert_eq!("", af.take_prefix(0));
+    /// ```
+    pub fn take_prefix(&self, max_bytes: usize) -> &str {
+        self.take_prefix_from(0, max_bytes)
+    }
+
+    /// Like [ArrForm::take_prefix], but starts at byte `offset` of the content
+    ///
+    /// This allows chunked transmission of long content over links with a fixed frame size. 
+    /// An empty string is returned if `offset` is at the end of the content or not on a 
+    /// character boundary.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(32, "sensor {} ok", 4711);
+    /// let mut offset = 0;
+    /// let mut chunks = 0;
+    /// while offset < af.as_bytes().len() {
+    ///     let chunk = af.take_prefix_from(offset, 5);
+    ///     offset += chunk.len();
+    ///     chunks += 1;
+    /// }
+    /// assert_eq!(3, chunks);
+    /// ```
+    pub fn take_prefix_from(&self, offset: usize, max_bytes: usize) -> &str {
+        let s = self.as_str();
+        let rest = match s.get(offset..) {
+            Some(rest) => rest,
+            None => return "",
+        };
+        let mut end = max_bytes.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        &rest[..end]
+    }
+
+    /// Get the byte index of the first occurrence of `pat` in the content
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(16, "temp:{}", 21);
+    /// assert_eq!(Some(4), af.find(':'));
+    /// assert_eq!(None, af.find('='));
+    /// ```
+    pub fn find(&self, pat: char) -> Option<usize> {
+        self.as_str().find(pat)
+    }
+
+    /// Get an iterator over the characters of the content
+    ///
+    /// Like [ArrForm::char_indices] this is available on the buffer itself, so generic code 
+    /// bounded on the buffer type can walk the content.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(16, "{}°C", 21);
+    /// assert_eq!(4, af.chars().count());
+    /// assert_eq!(Some((2, '°')), af.char_indices().find(|&(_, c)| !c.is_ascii()));
+    /// ```
+    pub fn chars(&self) -> Chars<'_> {
+        self.as_str().chars()
+    }
+
+    /// Get an iterator over the characters of the content and their byte positions
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        self.as_str().char_indices()
+    }
+
+    /// Get an iterator over the lines of the content
+    ///
+    /// Lines end with `"\n"` or `"\r\n"`, which are not part of the lines. One buffer can so 
+    /// hold a whole screen, which is drawn row by row.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let screen = arrform!(64, "T={}\r\nH={}%\nok", 21, 45);
+    /// let rows: Vec<&str> = screen.lines().collect();
+    /// assert_eq!(vec!["T=21", "H=45%", "ok"], rows);
+    /// ```
+    pub fn lines(&self) -> Lines<'_> {
+        self.as_str().lines()
+    }
+
+    /// Get the line with index `n`, counting from 0
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let screen = arrform!(64, "T={}\r\nH={}%\nok", 21, 45);
+    /// assert_eq!(Some("H=45%"), screen.line(1));
+    /// assert_eq!(None, screen.line(3));
+    /// assert_eq!(3, screen.line_count());
+    /// ```
+    pub fn line(&self, n: usize) -> Option<&str> {
+        self.lines().nth(n)
+    }
+
+    /// Get the number of lines of the content
+    pub fn line_count(&self) -> usize {
+        self.lines().count()
     }
 
     /// Get a reference to the result as a slice inside the buffer as bytes
     pub fn as_bytes(&self) -> &[u8] {
-        &self.buffer[..self.used]
+        self.filled()
+    }
+
+    /// Get a reference to the result as a null-terminated C string
+    ///
+    /// The terminating `\0` is placed behind the content and is not counted as part of it. An 
+    /// error is returned if there is no room left for the terminator or if the content already 
+    /// contains a `\0`.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let mut af = arrform!(16, "int {}", 42);
+    /// assert_eq!(b"int 42\0", af.as_cstr().unwrap().to_bytes_with_nul());
+    ///
+    /// let mut af = arrform!(6, "int {}", 42);
+    /// assert!(af.as_cstr().is_err());
+    /// ```
+    pub fn as_cstr(&mut self) -> Result<&CStr, fmt::Error> {
+        let used = self.used;
+        let buffer = self.buffer.as_mut_slice();
+        let Some(end) = buffer.get_mut(used) else {
+            return Err(fmt::Error);
+        };
+        *end = 0;
+        CStr::from_bytes_with_nul(buffer.get(..=used).unwrap_or_default()).map_err(|_| fmt::Error)
+    }
+
+    /// Forward the content to any other [fmt::Write] implementer
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let af = arrform!(16, "int {}", 42);
+    /// let mut other = ArrForm::<16>::new();
+    /// af.write_to_fmt(&mut other).unwrap();
+    /// assert_eq!("int 42", other.as_str());
+    /// ```
+    pub fn write_to_fmt<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.as_str())
+    }
+
+    /// Consume the buffer and return the storage together with the length of the content
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(8, "int {}", 42);
+    /// let (array, len) = af.into_inner();
+    /// assert_eq!(b"int 42", &array[..len]);
+    /// ```
+    pub fn into_inner(self) -> (S, usize) {
+        (self.buffer, self.used)
+    }
+
+    /// Get a mutable reference to the result as a slice inside the buffer as str
+    ///
+    /// In contrast to `as_bytes_mut`, edits through a str keep the content valid UTF-8.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        if self.raw || !S::is_trusted(Token) {
+            return valid_prefix_mut(self.filled_mut());
+        }
+        // We are really sure, that the buffer contains only valid utf8 characters
+        from_utf8_trusted_mut(self.filled_mut())
+    }
+
+    /// Convert the content to ASCII upper case in place
+    ///
+    /// Non-ASCII characters are left unchanged.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let mut af = arrform!(16, "crc={:x}", 0xbeefu16);
+    /// af.make_ascii_uppercase();
+    /// assert_eq!("CRC=BEEF", af.as_str());
+    /// af.make_ascii_lowercase();
+    /// assert_eq!("crc=beef", af.as_str());
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        self.filled_mut().make_ascii_uppercase()
+    }
+
+    /// Convert the content to ASCII lower case in place
+    ///
+    /// Non-ASCII characters are left unchanged.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.filled_mut().make_ascii_lowercase()
+    }
+
+    /// Get a mutable reference to the result as a slice inside the buffer as bytes
+    ///
+    /// This allows cheap post-processing of the formatted text, prefer [ArrForm::as_mut_str] 
+    /// where possible.
+    ///
+    /// # Safety
+    ///
+    /// Like with `str::as_bytes_mut`, the content must be valid UTF-8 when the borrow ends, 
+    /// otherwise [ArrForm::as_str] returns an invalid str.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let mut af = arrform!(16, "temp {}", 21);
+    /// let bytes = unsafe { af.as_bytes_mut() };
+    /// bytes[0].make_ascii_uppercase();
+    /// assert_eq!("Temp 21", af.as_str());
+    /// ```
+    #[cfg(not(feature = "safe"))]
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.filled_mut()
+    }
+}
+
+impl<const BUF_SIZE: usize, P: OverflowPolicy> Default for FormBuffer<[u8; BUF_SIZE], P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Storage + Clone, P: OverflowPolicy> Clone for FormBuffer<S, P> {
+    fn clone(&self) -> Self {
+        FormBuffer {
+            buffer: self.buffer.clone(),
+            used: self.used,
+            dropped: self.dropped,
+            high_water: self.high_water,
+            limit: self.limit,
+            raw: self.raw,
+            state: self.state,
+            policy: PhantomData,
+        }
+    }
+}
+
+/// Creates new buffer from existing text
+/// 
+/// Fails with [ArrFormError::Overflow] if the text does not fit into the buffer. An infallible 
+/// `From<&str>` cannot be offered additionally, because it would conflict with this 
+/// implementation.
+/// ```
+/// use arrform::{ArrForm, ArrFormError};
+/// 
+/// #[derive(Default)]
+/// struct Status {
+///     line: ArrForm<8>,
+/// }
+/// 
+/// let mut status = Status::default();
+/// status.line = ArrForm::try_from("ready").unwrap();
+/// let queued = status.line.clone();
+/// assert_eq!("ready", queued);
+/// 
+/// assert_eq!(
+///     Err(ArrFormError::Overflow { capacity: 8, needed_at_least: 9 }),
+///     ArrForm::<8>::try_from("not ready")
+/// );
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> TryFrom<&str> for FormBuffer<[u8; BUF_SIZE], P> {
+    type Error = ArrFormError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::with_prefix(s).map_err(|_| ArrFormError::Overflow {
+            capacity: BUF_SIZE,
+            needed_at_least: s.len(),
+        })
+    }
+}
+
+/// Creates new buffer from existing text
+/// 
+/// An error is returned if the text does not fit into the buffer.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::ArrForm;
+/// 
+/// let mut af: ArrForm<8> = "int".parse().unwrap();
+/// write!(af, " {}", 42).unwrap();
+/// assert_eq!("int 42", af.as_str());
+/// 
+/// assert!("int".parse::<ArrForm<3>>().is_ok());
+/// assert!("int".parse::<ArrForm<2>>().is_err());
+/// ```
+impl<const BUF_SIZE: usize, P: OverflowPolicy> FromStr for FormBuffer<[u8; BUF_SIZE], P> {
+    type Err = fmt::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::with_prefix(s)
+    }
+}
+
+/// Allows embedding the buffer in further formatting
+/// ```
+/// use arrform::arrform;
+///
+/// let inner = arrform!(16, "T={:.1}", 21.56);
+/// let outer = arrform!(32, "[{}] {:?}", inner, inner);
+/// assert_eq!("[T=21.6] \"T=21.6\"", outer.as_str());
+/// ```
+impl<S: Storage, P: OverflowPolicy> fmt::Display for FormBuffer<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> fmt::Debug for FormBuffer<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Compares the content of two buffers, independent of their size and storage
+///
+/// Like [Hash] and [Borrow], only the text returned by [ArrForm::as_str] is compared, so raw
+/// bytes behind it are ignored and the buffers work as keys of a map looked up by str.
+/// ```
+/// use arrform::arrform;
+///
+/// assert_eq!(arrform!(8, "{}", 42), arrform!(16, "{}", 42));
+/// assert_eq!(arrform!(8, "OK"), "OK");
+/// assert_eq!("OK", arrform!(8, "OK"));
+///
+/// let mut af = arrform!(8, "OK");
+/// af.write_bytes(&[0xff]).unwrap();
+/// assert_eq!(af, "OK");
+/// ```
+impl<S, P, S2, P2> PartialEq<FormBuffer<S2, P2>> for FormBuffer<S, P>
+where
+    S: Storage,
+    P: OverflowPolicy,
+    S2: Storage,
+    P2: OverflowPolicy,
+{
+    fn eq(&self, other: &FormBuffer<S2, P2>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> Eq for FormBuffer<S, P> {}
+
+impl<S: Storage, P: OverflowPolicy> PartialEq<str> for FormBuffer<S, P> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> PartialEq<&str> for FormBuffer<S, P> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> PartialEq<FormBuffer<S, P>> for str {
+    fn eq(&self, other: &FormBuffer<S, P>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> PartialEq<FormBuffer<S, P>> for &str {
+    fn eq(&self, other: &FormBuffer<S, P>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+/// Hashes like the contained str, consistent with [Borrow]
+impl<S: Storage, P: OverflowPolicy> Hash for FormBuffer<S, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> AsRef<str> for FormBuffer<S, P> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> AsRef<[u8]> for FormBuffer<S, P> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> Borrow<str> for FormBuffer<S, P> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Allows using the buffer wherever a str is expected
+/// ```
+/// use arrform::arrform;
+///
+/// fn draw(text: &str) -> usize {
+///     text.len()
+/// }
+///
+/// let af = arrform!(16, "int {}", 42);
+/// assert_eq!(6, draw(&af));
+/// assert!(af.starts_with("int"));
+/// ```
+impl<S: Storage, P: OverflowPolicy> Deref for FormBuffer<S, P> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
     }
 }
 
-impl<const BUF_SIZE: usize> fmt::Write for ArrForm<BUF_SIZE> {
+impl<S: Storage, P: OverflowPolicy> fmt::Write for FormBuffer<S, P> {
 
+    #[inline]
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        let remaining_buf = &mut self.buffer[self.used..];
-        let raw_s = s.as_bytes();
+        // Fast path, the whole text fits, which compiles to a plain memcpy
+        if s.len() <= self.limit - self.used {
+            return self.push_bytes(s.as_bytes());
+        }
+        self.write_text(s)
+    }
 
-        // Treat imminent buffer overflow
-        if raw_s.len() > remaining_buf.len() {
-            remaining_buf.copy_from_slice(&raw_s[..remaining_buf.len()]);
-            self.used += remaining_buf.len();
-            Err(fmt::Error)
-        } else {
-            remaining_buf[..raw_s.len()].copy_from_slice(raw_s);
-            self.used += raw_s.len();
-            Ok(())
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if c.is_ascii() && self.used < self.limit {
+            return self.push_bytes(&[c as u8]);
         }
+        self.write_text(c.encode_utf8(&mut [0; 4]))
     }
 }
 
@@ -131,13 +2044,252 @@ impl<const BUF_SIZE: usize> fmt::Write for ArrForm<BUF_SIZE> {
 /// let af = arrform!(64, "write some {}, int {}, float {:.3}", "stuff", 4711, 3.1415);
 /// assert_eq!("write some stuff, int 4711, float 3.142", af.as_str());
 /// ```
+/// 
+/// The size can be any constant expression, including a const generic parameter.
+/// 
+/// ```
+/// use arrform::arrform;
+/// 
+/// fn label<const N: usize>(value: u32) -> usize {
+///     arrform!(N, "value {}", value).as_str().len()
+/// }
+/// 
+/// assert_eq!(10, label::<16>(4711));
+/// assert_eq!("42", arrform!(2 * 8, "{}", 42).as_str());
+/// ```
 #[macro_export]
 macro_rules! arrform {
     ($size:expr, $($arg:tt)*) => {{
-        let mut af = $crate::ArrForm::<$size>::new();
+        let mut af = $crate::ArrForm::<{ $size }>::new();
 
         // Panic on buffer overflow
         af.format(format_args!($($arg)*)).expect("Buffer overflow");
         af
     }}
 }
+
+/// A macro like [arrform!], which terminates the text with a line ending
+/// 
+/// By default `"\r\n"` is appended. Another line ending can be given with `eol = ...` in front 
+/// of the format string. The macro panics if the text including the line ending does not fit 
+/// into the buffer.
+/// 
+/// ```
+/// use arrform::arrformln;
+/// 
+/// let af = arrformln!(16, "V={}", 3300);
+/// assert_eq!("V=3300\r\n", af.as_str());
+/// 
+/// let af = arrformln!(16, eol = "\n", "V={}", 3300);
+/// assert_eq!("V=3300\n", af.as_str());
+/// ```
+#[macro_export]
+macro_rules! arrformln {
+    ($size:expr, eol = $eol:expr, $($arg:tt)*) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        let eol: &str = $eol;
+
+        // Panic on buffer overflow
+        af.format(format_args!($($arg)*))
+            .and_then(|_| af.extend_str(&[eol]))
+            .expect("Buffer overflow");
+        af
+    }};
+    ($size:expr, $($arg:tt)*) => {
+        $crate::arrformln!($size, eol = "\r\n", $($arg)*)
+    };
+}
+
+/// A macro like [arrform!], which formats into an existing buffer or byte slice
+/// 
+/// No new buffer is placed on the stack, so a buffer can be reused in a loop. The previous 
+/// content is replaced. The macro returns a `Result` with the formatted text, an error is 
+/// returned if the text does not fit.
+/// 
+/// ```
+/// use arrform::{arrform_into, ArrForm};
+/// 
+/// let mut af = ArrForm::<16>::new();
+/// for v in [1, 22, 333] {
+///     let s = arrform_into!(af, "v={}", v).unwrap();
+///     assert!(s.starts_with("v="));
+/// }
+/// assert_eq!("v=333", af.as_str());
+/// 
+/// let mut buf = [0u8; 8];
+/// assert_eq!(Ok("v=4711"), arrform_into!(&mut buf, "v={}", 4711));
+/// assert!(arrform_into!(buf, "v={}", 4711.125).is_err());
+/// ```
+#[macro_export]
+macro_rules! arrform_into {
+    ($target:expr, $($arg:tt)*) => {{
+        use $crate::FormatTarget as _;
+        ($target).format_target(format_args!($($arg)*))
+    }}
+}
+
+/// A macro like [arrform!], which clips the text instead of panicking
+/// 
+/// The text is silently cut to the size of the buffer, see [ArrForm::format_lossy]. This 
+/// keeps the panic machinery out of the program code.
+/// 
+/// ```
+/// use arrform::arrform_lossy;
+/// 
+/// let af = arrform_lossy!(6, "T={:.1}", 21.56);
+/// assert_eq!("T=21.6", af.as_str());
+/// 
+/// let af = arrform_lossy!(4, "T={:.1}", 21.56);
+/// assert_eq!("T=21", af.as_str());
+/// assert!(af.truncated());
+/// ```
+#[macro_export]
+macro_rules! arrform_lossy {
+    ($size:expr, $($arg:tt)*) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        af.format_lossy(format_args!($($arg)*));
+        af
+    }}
+}
+
+/// A macro like [arrform!], which returns a `Result` instead of panicking
+/// 
+/// The error tells whether the buffer was chosen too small and how large it should have been, 
+/// see [ArrFormError].
+/// 
+/// ```
+/// use arrform::{try_arrform, ArrFormError};
+/// 
+/// let af = try_arrform!(16, "int {}", 4711).unwrap();
+/// assert_eq!("int 4711", af.as_str());
+/// 
+/// match try_arrform!(4, "int {}", 4711) {
+///     Err(ArrFormError::Overflow { capacity, needed_at_least }) => {
+///         assert_eq!(4, capacity);
+///         assert_eq!(8, needed_at_least);
+///     }
+///     _ => panic!("An overflow should occur"),
+/// }
+/// ```
+#[macro_export]
+macro_rules! try_arrform {
+    ($size:expr, $($arg:tt)*) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        af.format_detailed(format_args!($($arg)*)).map(|_| af)
+    }}
+}
+
+/// A macro like [arrform!], which returns an `Option` instead of panicking
+/// 
+/// `None` is returned if the buffer is chosen too small. Use [try_arrform!] to get the reason 
+/// of the failure.
+/// 
+/// ```
+/// use arrform::arrform_opt;
+/// 
+/// if let Some(af) = arrform_opt!(16, "int {}", 4711) {
+///     assert_eq!("int 4711", af.as_str());
+/// }
+/// assert!(arrform_opt!(4, "int {}", 4711).is_none());
+/// ```
+#[macro_export]
+macro_rules! arrform_opt {
+    ($size:expr, $($arg:tt)*) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        af.format(format_args!($($arg)*)).ok().map(|_| af)
+    }}
+}
+
+/// A macro, which places an empty buffer in static memory instead of on the stack
+/// 
+/// Large buffers, e.g. for JSON telemetry, would overflow the small stacks of tasks. The 
+/// macro returns `Some(&'static mut ArrForm)` when it is executed the first time and `None` 
+/// afterwards, so there is never more than one reference to the buffer. The linker section 
+/// can be selected with `link_section = ...`, e.g. to place the buffer in CCM or DMA-capable 
+/// RAM.
+/// 
+/// ```
+/// use arrform::{arrform_static, ArrForm};
+/// 
+/// fn telemetry() -> Option<&'static mut ArrForm<4096>> {
+///     arrform_static!(4096)
+/// }
+/// 
+/// let af = telemetry().unwrap();
+/// af.format(format_args!("{{\"temp\":{}}}", 21)).unwrap();
+/// assert_eq!("{\"temp\":21}", af.as_str());
+/// assert!(telemetry().is_none());
+/// ```
+/// 
+/// ```ignore
+/// let af = arrform_static!(2048, link_section = ".ccmram").unwrap();
+/// ```
+#[cfg(not(feature = "safe"))]
+#[macro_export]
+macro_rules! arrform_static {
+    (@take $size:expr, $(#[$attr:meta])?) => {{
+        use ::core::sync::atomic::{AtomicBool, Ordering};
+
+        static TAKEN: AtomicBool = AtomicBool::new(false);
+        $(#[$attr])?
+        static mut BUF: $crate::ArrForm<{ $size }> = $crate::ArrForm::new();
+
+        if TAKEN.swap(true, Ordering::AcqRel) {
+            None
+        } else {
+            // Safety: The flag was not set before, so this is the only reference to the buffer
+            Some(unsafe { &mut *::core::ptr::addr_of_mut!(BUF) })
+        }
+    }};
+    ($size:expr, link_section = $section:literal) => {
+        $crate::arrform_static!(@take $size, #[link_section = $section])
+    };
+    ($size:expr) => {
+        $crate::arrform_static!(@take $size,)
+    };
+}
+
+/// A macro to concatenate several formatted fragments in one buffer on the stack
+/// 
+/// Each fragment is given in square brackets and is formatted like the arguments of [arrform!]. 
+/// The fragments are written one after the other into the same [ArrForm]. The macro panics if 
+/// the combined content does not fit into the buffer.
+/// 
+/// ```
+/// use arrform::arrform_concat;
+/// 
+/// let af = arrform_concat!(64, ["temp {:.1}", 21.56], [", "], ["hum {}%", 45]);
+/// assert_eq!("temp 21.6, hum 45%", af.as_str());
+/// ```
+#[macro_export]
+macro_rules! arrform_concat {
+    ($size:expr, $([$($arg:tt)*]),+ $(,)?) => {{
+        // Panic on buffer overflow
+        $crate::try_arrform_concat!($size, $([$($arg)*]),+).expect("Buffer overflow")
+    }}
+}
+
+/// A macro like [arrform_concat!], which returns a `Result` instead of panicking
+/// 
+/// ```
+/// use arrform::try_arrform_concat;
+/// 
+/// let af = try_arrform_concat!(16, ["{}", 4711], ["-{}", 42]).unwrap();
+/// assert_eq!("4711-42", af.as_str());
+/// assert!(try_arrform_concat!(4, ["{}", 4711], ["-{}", 42]).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_arrform_concat {
+    ($size:expr, $([$($arg:tt)*]),+ $(,)?) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        let mut result: ::core::fmt::Result = Ok(());
+
+        // Stop at the first fragment, which does not fit
+        $(
+            if result.is_ok() {
+                result = af.append(format_args!($($arg)*));
+            }
+        )+
+        result.map(|_| af)
+    }}
+}
@@ -0,0 +1,55 @@
+//! Backend for the `log` crate
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::ArrForm;
+
+/// Logger, which formats each record into a buffer of `N` bytes and passes it to a function
+///
+/// A record is written as `LEVEL target: message` followed by `"\r\n"`. Text that does not fit
+/// is clipped, but the line ending is kept.
+/// ```
+/// use arrform::ArrLogger;
+/// use log::LevelFilter;
+///
+/// fn uart_send(bytes: &[u8]) {
+///     assert_eq!(b"INFO app: T=21.6\r\n", bytes);
+/// }
+///
+/// static LOGGER: ArrLogger<64> = ArrLogger::new(uart_send, LevelFilter::Info);
+///
+/// log::set_logger(&LOGGER).unwrap();
+/// log::set_max_level(LevelFilter::Info);
+/// log::info!(target: "app", "T={:.1}", 21.56);
+/// log::debug!(target: "app", "not sent");
+/// ```
+pub struct ArrLogger<const N: usize> {
+    output: fn(&[u8]),
+    level: LevelFilter,
+}
+
+impl<const N: usize> ArrLogger<N> {
+    /// Creates a logger, which passes records up to `level` to `output`
+    pub const fn new(output: fn(&[u8]), level: LevelFilter) -> Self {
+        ArrLogger { output, level }
+    }
+}
+
+impl<const N: usize> Log for ArrLogger<N> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let mut af = ArrForm::<N>::new();
+        let args = format_args!("{} {}: {}", record.level(), record.target(), record.args());
+        let _ = af.format_limited(N.saturating_sub(2), args);
+        let _ = af.push_str("\r\n");
+        (self.output)(af.as_bytes());
+    }
+
+    fn flush(&self) {}
+}
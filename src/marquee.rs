@@ -0,0 +1,55 @@
+//! Scrolling status lines, which are longer than the display
+
+use core::{
+    iter::{Chain, Cycle, Skip, Take},
+    str::Chars,
+};
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Iterate over the characters visible in a window of `width` characters
+    ///
+    /// The content is shown from character `offset` on and repeats after `separator`, so
+    /// incrementing `offset` each tick scrolls the text smoothly without formatting it again.
+    /// Content, which fits into the window, is shown unchanged.
+    /// ```
+    /// use arrform::{arrform, ArrForm};
+    ///
+    /// let status = arrform!(32, "WiFi {} dBm", -67);
+    /// let mut lcd = ArrForm::<8>::new();
+    /// for (tick, expected) in [(0, "WiFi -67"), (10, "Bm | WiF"), (14, " WiFi -6")] {
+    ///     lcd.clear();
+    ///     lcd.extend(status.marquee(8, tick, " | "));
+    ///     assert_eq!(expected, lcd.as_str());
+    /// }
+    ///
+    /// let short = arrform!(8, "OK");
+    /// assert!(short.marquee(8, 5, " | ").eq("OK".chars()));
+    /// ```
+    pub fn marquee<'a>(&'a self, width: usize, offset: usize, separator: &'a str) -> Marquee<'a> {
+        let text = self.as_str();
+        let len = text.chars().count();
+        // Only text longer than the window, so never empty, scrolls
+        let (separator, start, width) = if len <= width {
+            ("", 0, len)
+        } else {
+            (separator, offset % (len + separator.chars().count()), width)
+        };
+        let chars = text.chars().chain(separator.chars()).cycle().skip(start).take(width);
+        Marquee { chars }
+    }
+}
+
+/// Iterator over the visible characters, see [ArrForm::marquee](crate::FormBuffer::marquee)
+pub struct Marquee<'a> {
+    chars: Take<Skip<Cycle<Chain<Chars<'a>, Chars<'a>>>>>,
+}
+
+impl Iterator for Marquee<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.chars.next()
+    }
+}
@@ -0,0 +1,105 @@
+//! Network addresses for status screens
+
+use core::fmt;
+
+use crate::{radix::digit, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append an IPv4 address in dotted decimal notation
+    ///
+    /// `core::net::Ipv4Addr::octets` provides the bytes of an address.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<16>::new();
+    /// af.write_ipv4([192, 168, 0, 17]).unwrap();
+    /// assert_eq!("192.168.0.17", af.as_str());
+    /// ```
+    pub fn write_ipv4(&mut self, octets: [u8; 4]) -> fmt::Result {
+        for (i, octet) in octets.into_iter().enumerate() {
+            if i > 0 {
+                self.write_ascii(b".")?;
+            }
+            self.put_u8(octet)?;
+        }
+        Ok(())
+    }
+
+    /// Append an IPv6 address in the canonical notation of RFC 5952
+    ///
+    /// The hexadecimal digits are lower case, leading zeros are omitted and the longest run of 
+    /// zero groups is shortened to `::`.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<40>::new();
+    /// af.write_ipv6([0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01]).unwrap();
+    /// assert_eq!("2001:db8::1", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_ipv6([0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0x02, 0x1a, 0, 0, 0, 0, 0xab, 0xcd]).unwrap();
+    /// assert_eq!("fe80::21a:0:0:abcd", af.as_str());
+    /// ```
+    pub fn write_ipv6(&mut self, octets: [u8; 16]) -> fmt::Result {
+        let mut groups = [0u16; 8];
+        for (group, pair) in groups.iter_mut().zip(octets.chunks_exact(2)) {
+            if let &[high, low] = pair {
+                *group = u16::from_be_bytes([high, low]);
+            }
+        }
+
+        // Longest run of at least two zero groups, the first one wins a tie
+        let (mut zeros, mut zeros_len) = (groups.len(), 1);
+        let mut i = 0;
+        while i < groups.len() {
+            let len = groups.iter().skip(i).take_while(|&&g| g == 0).count();
+            if len > zeros_len {
+                (zeros, zeros_len) = (i, len);
+            }
+            i += len.max(1);
+        }
+
+        let mut i = 0;
+        while i < groups.len() {
+            if i == zeros {
+                self.write_ascii(b"::")?;
+                i += zeros_len;
+                continue;
+            }
+            if i > 0 && i != zeros + zeros_len {
+                self.write_ascii(b":")?;
+            }
+            self.write_hex_group(groups.get(i).copied().unwrap_or_default())?;
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// Append a MAC address as upper case hexadecimal bytes separated by colons
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<20>::new();
+    /// af.write_mac([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]).unwrap();
+    /// assert_eq!("00:1A:2B:3C:4D:5E", af.as_str());
+    /// ```
+    pub fn write_mac(&mut self, bytes: [u8; 6]) -> fmt::Result {
+        for (i, b) in bytes.into_iter().enumerate() {
+            if i > 0 {
+                self.write_ascii(b":")?;
+            }
+            self.write_ascii(&[digit(u32::from(b >> 4)), digit(u32::from(b & 0xf))])?;
+        }
+        Ok(())
+    }
+
+    /// Append a group of an IPv6 address in lower case without leading zeros
+    fn write_hex_group(&mut self, group: u16) -> fmt::Result {
+        let mut digits = [0u8; 4];
+        for (i, d) in digits.iter_mut().enumerate() {
+            *d = digit(u32::from(group >> (12 - 4 * i)) & 0xf).to_ascii_lowercase();
+        }
+        let skip = (group.leading_zeros() / 4).min(3) as usize;
+        self.write_ascii(digits.get(skip..).unwrap_or_default())
+    }
+}
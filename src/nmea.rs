@@ -0,0 +1,83 @@
+//! NMEA 0183 sentences
+
+use core::fmt::{self, Write};
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a builder, which appends an NMEA 0183 sentence to this buffer
+    ///
+    /// The sentence starts with `$` and `address`, like `GPGGA`.
+    pub fn nmea(&mut self, address: &str) -> NmeaForm<'_, S, P> {
+        let result = self.write_ascii(b"$");
+        let mut nmea = NmeaForm { buffer: self, checksum: 0, result };
+        if nmea.result.is_ok() {
+            nmea.result = nmea.write_str(address);
+        }
+        nmea
+    }
+}
+
+/// Builder for NMEA 0183 sentences in a buffer
+///
+/// The XOR checksum of all characters between `$` and `*` is accumulated while the fields are
+/// formatted. The first error is kept and returned by [NmeaForm::finish], later calls do
+/// nothing.
+/// ```
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<82>::new();
+/// af.nmea("GPGLL")
+///     .field(format_args!("{:.2}", 4916.45))
+///     .field_str("N")
+///     .field(format_args!("{:05.2}", 12311.12))
+///     .field_str("W")
+///     .field(format_args!("{:09.2}", 225444.0))
+///     .field_str("A")
+///     .finish()
+///     .unwrap();
+/// assert_eq!("$GPGLL,4916.45,N,12311.12,W,225444.00,A*1F\r\n", af.as_str());
+/// ```
+pub struct NmeaForm<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+    checksum: u8,
+    result: fmt::Result,
+}
+
+impl<S: Storage, P: OverflowPolicy> NmeaForm<'_, S, P> {
+    /// Append a formatted field
+    pub fn field(&mut self, args: fmt::Arguments) -> &mut Self {
+        if self.result.is_ok() {
+            self.result = self.write_str(",").and_then(|_| fmt::write(self, args));
+        }
+        self
+    }
+
+    /// Append a text field
+    pub fn field_str(&mut self, s: &str) -> &mut Self {
+        if self.result.is_ok() {
+            self.result = self.write_str(",").and_then(|_| self.write_str(s));
+        }
+        self
+    }
+
+    /// Get the checksum of the sentence so far
+    pub fn checksum(&self) -> u8 {
+        self.checksum
+    }
+
+    /// Append `*`, the checksum and `"\r\n"` and get the result of all calls
+    pub fn finish(&mut self) -> fmt::Result {
+        self.result?;
+        self.buffer.write_ascii(b"*")?;
+        self.buffer.write_hex(self.checksum as u32, 2)?;
+        self.buffer.write_ascii(b"\r\n")
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> fmt::Write for NmeaForm<'_, S, P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.checksum = s.bytes().fold(self.checksum, |checksum, b| checksum ^ b);
+        self.buffer.write_text(s)
+    }
+}
@@ -0,0 +1,76 @@
+//! Formatting into a fixed region of the buffer
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Format numbers and strings at byte position `offset`, overwriting the text there
+    ///
+    /// The rest of the content is kept, so a buffer padded with 
+    /// [ArrForm::pad_to](crate::FormBuffer::pad_to) serves as a character framebuffer for text 
+    /// displays, where each value has a known column. Text behind the end of the content 
+    /// extends it. A character, which is only partly overwritten, is replaced by spaces. An 
+    /// error is returned and nothing is written if `offset` lies behind the content or not on a 
+    /// character boundary. On overflow the text is cut at the end of the buffer.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut lcd = ArrForm::<20>::new();
+    /// lcd.pad_to(20, ' ').unwrap();
+    /// lcd.format_at(0, format_args!("RPM: {:4}", 1234)).unwrap();
+    /// lcd.format_at(11, format_args!("TEMP: {}", 56)).unwrap();
+    /// assert_eq!("RPM: 1234  TEMP: 56 ", lcd.as_str());
+    ///
+    /// lcd.format_at(5, format_args!("{:4}", 987)).unwrap();
+    /// assert_eq!("RPM:  987  TEMP: 56 ", lcd.as_str());
+    /// assert!(lcd.format_at(17, format_args!("{}", 1024)).is_err());
+    /// ```
+    pub fn format_at(&mut self, offset: usize, args: fmt::Arguments) -> fmt::Result {
+        if !self.as_str().is_char_boundary(offset) {
+            return Err(fmt::Error);
+        }
+        self.dropped = 0;
+        let mut region = Region { buffer: self, pos: offset };
+        let result = fmt::write(&mut region, args);
+        let end = region.pos;
+
+        // Clear the remains of an old character cut by the end of the new text
+        let used = self.used;
+        for b in self.buffer.as_mut_slice().get_mut(end..used).unwrap_or_default() {
+            if *b & 0xc0 != 0x80 {
+                break;
+            }
+            *b = b' ';
+        }
+        result
+    }
+}
+
+/// Writer, which overwrites the buffer starting at `pos`
+struct Region<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+    pos: usize,
+}
+
+impl<S: Storage, P: OverflowPolicy> fmt::Write for Region<'_, S, P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let af = &mut *self.buffer;
+        let mut fit = s.len().min(af.limit - self.pos);
+        while !s.is_char_boundary(fit) {
+            fit -= 1;
+        }
+        let end = self.pos + fit;
+        let dst = af.buffer.as_mut_slice().get_mut(self.pos..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes().get(..fit).unwrap_or_default());
+        self.pos = end;
+        af.used = af.used.max(end);
+
+        if fit < s.len() {
+            af.dropped += s.len() - fit;
+            af.on_overflow()
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Scrolling text panel for `embedded-graphics` displays
+
+use core::fmt;
+
+use embedded_graphics::{
+    prelude::{DrawTarget, Point},
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable,
+};
+
+use crate::ArrForm;
+
+/// Text panel of `H` lines with up to `W` bytes each
+///
+/// New lines are added at the bottom with [TextPanel::println]. If the panel is full, the
+/// content scrolls up by one line. Text, which is too long for a line, is clipped.
+/// ```
+/// use arrform::TextPanel;
+/// use embedded_graphics::{
+///     mock_display::MockDisplay, mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::BinaryColor, prelude::Point,
+/// };
+///
+/// let mut panel = TextPanel::<10, 3>::new();
+/// for t in 20..24 {
+///     panel.println(format_args!("temp {}", t)).unwrap();
+/// }
+/// assert!(panel.println(format_args!("humidity {}%", 45)).is_err());
+/// let mut lines = panel.lines();
+/// assert_eq!(Some("temp 22"), lines.next());
+/// assert_eq!(Some("temp 23"), lines.next());
+/// assert_eq!(Some("humidity 4"), lines.next());
+/// assert_eq!(None, lines.next());
+///
+/// let mut display = MockDisplay::new();
+/// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+/// panel.draw(style, Point::zero(), &mut display).unwrap();
+/// ```
+pub struct TextPanel<const W: usize, const H: usize> {
+    lines: [ArrForm<W>; H],
+    count: usize,
+}
+
+impl<const W: usize, const H: usize> TextPanel<W, H> {
+    /// Creates an empty panel
+    pub const fn new() -> Self {
+        TextPanel { lines: [const { ArrForm::new() }; H], count: 0 }
+    }
+
+    /// Format numbers and strings as a new line at the bottom of the panel
+    ///
+    /// If the panel is full, the top line is dropped. An error is returned if the text had to
+    /// be clipped, the clipped line is shown anyway.
+    pub fn println(&mut self, args: fmt::Arguments) -> fmt::Result {
+        if self.count == H {
+            self.lines.rotate_left(1);
+            self.count -= 1;
+        }
+        match self.lines.get_mut(self.count) {
+            Some(line) => {
+                self.count += 1;
+                line.format(args)
+            }
+            // A panel without lines cannot show anything
+            None => Err(fmt::Error),
+        }
+    }
+
+    /// Format numbers and strings behind the text of the bottom line
+    ///
+    /// A new line is started if the panel is empty.
+    pub fn print(&mut self, args: fmt::Arguments) -> fmt::Result {
+        match self.count.checked_sub(1).and_then(|last| self.lines.get_mut(last)) {
+            Some(line) => line.append(args),
+            None => self.println(args),
+        }
+    }
+
+    /// Iterate over the lines, top first
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().take(self.count).map(|line| line.as_str())
+    }
+
+    /// Get the number of lines shown
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Check if the panel is empty
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Remove all lines
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Draw the lines with `style`, the top left corner of the first line is at `origin`
+    ///
+    /// The lines are placed below each other with the line height of the style.
+    pub fn draw<S, D>(&self, style: S, origin: Point, target: &mut D) -> Result<(), D::Error>
+    where
+        S: TextRenderer<Color = D::Color> + Clone,
+        D: DrawTarget,
+    {
+        let line_height = style.line_height() as i32;
+        let mut position = origin;
+        for line in self.lines() {
+            Text::with_baseline(line, position, style.clone(), Baseline::Top).draw(target)?;
+            position.y += line_height;
+        }
+        Ok(())
+    }
+}
+
+impl<const W: usize, const H: usize> Default for TextPanel<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,153 @@
+//! Overflow policies selecting the behavior of [ArrForm](crate::ArrForm) at the type level
+
+use core::fmt;
+
+use crate::{FormBuffer, Storage};
+
+/// Decides what happens, if formatted text does not fit into the buffer
+///
+/// The policy is a type parameter of [ArrForm](crate::ArrForm), so the behavior is chosen once 
+/// at construction and the write path is monomorphized per policy. When an overflow occurs, the 
+/// buffer has already been filled with as many bytes as fit.
+pub trait OverflowPolicy {
+    /// Setting kept in each buffer, `()` for policies which decide at the type level
+    type State: Copy;
+
+    /// Setting of a new buffer
+    const STATE: Self::State;
+
+    /// Called from `write_str` when the buffer overflows
+    fn on_overflow() -> fmt::Result;
+
+    /// Called with the setting of the buffer when it overflows
+    ///
+    /// Policies, which decide at the type level, ignore the setting.
+    fn on_overflow_in(state: Self::State) -> fmt::Result {
+        let _ = state;
+        Self::on_overflow()
+    }
+}
+
+/// Stops formatting and returns an error on overflow (default behavior)
+/// ```
+/// use arrform::{ArrForm, ErrorPolicy};
+///
+/// let mut af = ArrForm::<4, ErrorPolicy>::new();
+/// assert!(af.format(format_args!("{}", 123456)).is_err());
+/// assert_eq!("1234", af.as_str());
+/// ```
+pub struct ErrorPolicy;
+
+impl OverflowPolicy for ErrorPolicy {
+    type State = ();
+    const STATE: () = ();
+
+    fn on_overflow() -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+/// Silently keeps the part of the text that fits into the buffer
+/// ```
+/// use arrform::{ArrForm, SaturatePolicy};
+///
+/// let mut af = ArrForm::<4, SaturatePolicy>::new();
+/// assert!(af.format(format_args!("{}", 123456)).is_ok());
+/// assert_eq!("1234", af.as_str());
+/// assert!(af.truncated());
+/// ```
+pub struct SaturatePolicy;
+
+impl OverflowPolicy for SaturatePolicy {
+    type State = ();
+    const STATE: () = ();
+
+    fn on_overflow() -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// Panics on overflow
+/// ```should_panic
+/// use arrform::{ArrForm, PanicPolicy};
+///
+/// let mut af = ArrForm::<4, PanicPolicy>::new();
+/// let _ = af.format(format_args!("{}", 123456));
+/// ```
+pub struct PanicPolicy;
+
+impl OverflowPolicy for PanicPolicy {
+    type State = ();
+    const STATE: () = ();
+
+    // Panicking is the purpose of this policy, it is only used if selected by the caller
+    #[allow(clippy::panic)]
+    fn on_overflow() -> fmt::Result {
+        panic!("Buffer overflow")
+    }
+}
+
+/// Behavior on overflow of a buffer with the [RuntimePolicy]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// Stop formatting and return an error, like [ErrorPolicy]
+    #[default]
+    Error,
+    /// Keep the part of the text that fits, like [SaturatePolicy]
+    Truncate,
+    /// Panic, like [PanicPolicy]
+    Panic,
+}
+
+/// Selects the behavior on overflow per buffer at runtime, see [OverflowMode]
+///
+/// Library code can format into a buffer of the caller and honor the behavior chosen by the
+/// caller. The mode is set with
+/// [ArrForm::set_overflow_mode](crate::FormBuffer::set_overflow_mode), the default is
+/// [OverflowMode::Error].
+/// ```
+/// use arrform::{ArrForm, OverflowMode, RuntimePolicy};
+///
+/// fn report(af: &mut ArrForm<4, RuntimePolicy>) -> core::fmt::Result {
+///     af.format(format_args!("{}", 123456))
+/// }
+///
+/// let mut af = ArrForm::<4, RuntimePolicy>::new();
+/// assert!(report(&mut af).is_err());
+///
+/// af.set_overflow_mode(OverflowMode::Truncate);
+/// assert!(report(&mut af).is_ok());
+/// assert_eq!("1234", af.as_str());
+/// ```
+pub struct RuntimePolicy;
+
+impl OverflowPolicy for RuntimePolicy {
+    type State = OverflowMode;
+    const STATE: OverflowMode = OverflowMode::Error;
+
+    fn on_overflow() -> fmt::Result {
+        Self::on_overflow_in(Self::STATE)
+    }
+
+    // Panicking is only done if the caller selected it
+    #[allow(clippy::panic)]
+    fn on_overflow_in(mode: OverflowMode) -> fmt::Result {
+        match mode {
+            OverflowMode::Error => Err(fmt::Error),
+            OverflowMode::Truncate => Ok(()),
+            OverflowMode::Panic => panic!("Buffer overflow"),
+        }
+    }
+}
+
+impl<S: Storage> FormBuffer<S, RuntimePolicy> {
+    /// Select the behavior on overflow of this buffer
+    pub fn set_overflow_mode(&mut self, mode: OverflowMode) {
+        self.state = mode;
+    }
+
+    /// Get the behavior on overflow of this buffer
+    pub fn overflow_mode(&self) -> OverflowMode {
+        self.state
+    }
+}
@@ -0,0 +1,98 @@
+//! Fixed pool of format buffers
+
+use core::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::ArrForm;
+
+/// Pool of `COUNT` buffers with `N` bytes each
+///
+/// A buffer is taken with [FormPool::take] and returned to the pool when the handle is dropped.
+/// The pool can be placed in a `static`, so interrupt handlers can format messages without
+/// stack pressure and hand them over to the main loop.
+/// ```
+/// use arrform::FormPool;
+///
+/// static POOL: FormPool<32, 2> = FormPool::new();
+///
+/// let mut msg = POOL.take().unwrap();
+/// msg.format(format_args!("irq {}", 7)).unwrap();
+/// let other = POOL.take().unwrap();
+/// assert!(POOL.take().is_none());
+///
+/// assert_eq!("irq 7", msg.as_str());
+/// drop(msg);
+/// assert_eq!(1, POOL.available());
+/// assert!(POOL.take().unwrap().is_empty());
+/// # drop(other);
+/// ```
+pub struct FormPool<const N: usize, const COUNT: usize> {
+    buffers: [UnsafeCell<ArrForm<N>>; COUNT],
+    taken: [AtomicBool; COUNT],
+}
+
+// Safety: A buffer is only accessed through the single handle, which has set its flag
+unsafe impl<const N: usize, const COUNT: usize> Sync for FormPool<N, COUNT> {}
+
+impl<const N: usize, const COUNT: usize> FormPool<N, COUNT> {
+    /// Creates a pool of empty buffers
+    pub const fn new() -> Self {
+        FormPool {
+            buffers: [const { UnsafeCell::new(ArrForm::new()) }; COUNT],
+            taken: [const { AtomicBool::new(false) }; COUNT],
+        }
+    }
+
+    /// Take an empty buffer from the pool, if one is available
+    pub fn take(&self) -> Option<PoolForm<'_, N>> {
+        self.taken.iter().zip(&self.buffers).find_map(|(taken, buffer)| {
+            if taken.swap(true, Ordering::Acquire) {
+                return None;
+            }
+            // Safety: The flag was not set before, so there is no other reference to the buffer
+            let form = unsafe { &mut *buffer.get() };
+            form.clear();
+            Some(PoolForm { form, taken })
+        })
+    }
+
+    /// Get the number of buffers, which can be taken
+    pub fn available(&self) -> usize {
+        self.taken.iter().filter(|taken| !taken.load(Ordering::Relaxed)).count()
+    }
+}
+
+impl<const N: usize, const COUNT: usize> Default for FormPool<N, COUNT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Buffer taken from a [FormPool], which is returned on drop
+pub struct PoolForm<'a, const N: usize> {
+    form: &'a mut ArrForm<N>,
+    taken: &'a AtomicBool,
+}
+
+impl<const N: usize> Deref for PoolForm<'_, N> {
+    type Target = ArrForm<N>;
+
+    fn deref(&self) -> &ArrForm<N> {
+        self.form
+    }
+}
+
+impl<const N: usize> DerefMut for PoolForm<'_, N> {
+    fn deref_mut(&mut self) -> &mut ArrForm<N> {
+        self.form
+    }
+}
+
+impl<const N: usize> Drop for PoolForm<'_, N> {
+    fn drop(&mut self) {
+        self.taken.store(false, Ordering::Release);
+    }
+}
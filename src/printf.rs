@@ -0,0 +1,301 @@
+//! Runtime printf-style templates
+
+use core::fmt::{self, Write};
+
+use crate::{ArrForm, FormBuffer, OverflowPolicy, Storage};
+
+/// Argument of a runtime template, see [FormBuffer::printf]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrintfArg<'a> {
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'a str),
+    Char(char),
+}
+
+macro_rules! printf_arg_from {
+    ($variant:ident, $target:ty, $($t:ty),+) => {$(
+        impl From<$t> for PrintfArg<'_> {
+            fn from(v: $t) -> Self {
+                PrintfArg::$variant(v as $target)
+            }
+        }
+    )+};
+}
+
+printf_arg_from!(Int, i64, i8, i16, i32, i64, isize);
+printf_arg_from!(Uint, u64, u8, u16, u32, u64, usize);
+printf_arg_from!(Float, f64, f32, f64);
+
+impl From<char> for PrintfArg<'_> {
+    fn from(v: char) -> Self {
+        PrintfArg::Char(v)
+    }
+}
+
+impl<'a> From<&'a str> for PrintfArg<'a> {
+    fn from(v: &'a str) -> Self {
+        PrintfArg::Str(v)
+    }
+}
+
+/// Limit of widths and precisions, larger numbers in a template are reduced to it
+const MAX_WIDTH: usize = 0xffff;
+
+/// Flags, width and precision of a conversion
+#[derive(Default)]
+struct Spec {
+    left: bool,
+    zero: bool,
+    plus: bool,
+    space: bool,
+    alt: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append text from a template given at runtime, in the style of C's `printf`
+    ///
+    /// Conversions are `%d`, `%i`, `%u`, `%x`, `%X`, `%o`, `%f`, `%e`, `%E`, `%s`, `%c` and `%%`,
+    /// with the flags `-`, `0`, `+`, space and `#`, a width and a precision. Length modifiers
+    /// like `l` or `h` are accepted and ignored. `%c` takes a `char` or an integer character
+    /// code. A single converted value may be at most 64 bytes long, widths and precisions are
+    /// limited to 65535.
+    ///
+    /// An error is returned if the template is invalid, if the arguments do not match the
+    /// conversions or if the buffer overflows.
+    /// ```
+    /// use arrform::{ArrForm, PrintfArg, SaturatePolicy};
+    ///
+    /// let mut af = ArrForm::<64>::new();
+    /// let template = "T=%d C, RH=%.1f%%, id %04X";
+    /// af.printf(template, &[(-4).into(), 45.66.into(), 42u16.into()]).unwrap();
+    /// assert_eq!("T=-4 C, RH=45.7%, id 002A", af.as_str());
+    ///
+    /// af.clear();
+    /// af.printf("[%-6s|%6.2s|%+05d]", &["abc".into(), "xyz".into(), 7.into()]).unwrap();
+    /// assert_eq!("[abc   |    xy|+0007]", af.as_str());
+    ///
+    /// af.clear();
+    /// let args = [1500.0.into(), 0.25.into(), 'o'.into(), 107.into(), 7.into()];
+    /// af.printf("%.2e %E %c%c %06.3d", &args).unwrap();
+    /// assert_eq!("1.50e+03 2.500000E-01 ok    007", af.as_str());
+    ///
+    /// af.clear();
+    /// assert!(af.printf("%d %d", &[PrintfArg::Int(1)]).is_err());
+    /// assert!(af.printf("%d", &["text".into()]).is_err());
+    ///
+    /// // Huge widths do not overflow, the padding stops at the end of the buffer
+    /// let mut af = ArrForm::<8, SaturatePolicy>::new();
+    /// af.printf("%99999999999999999999999d|%.99999999999999999999s", &[1.into(), "ab".into()])
+    ///     .unwrap();
+    /// assert_eq!("        ", af.as_str());
+    /// ```
+    pub fn printf(&mut self, template: &str, args: &[PrintfArg]) -> fmt::Result {
+        let mut args = args.iter();
+        let mut rest = template;
+        while let Some(pos) = rest.find('%') {
+            self.write_text(&rest[..pos])?;
+            let mut chars = rest[pos + 1..].char_indices();
+            let mut spec = Spec::default();
+
+            let mut next = chars.next();
+            while let Some((_, c)) = next {
+                match c {
+                    '-' => spec.left = true,
+                    '0' => spec.zero = true,
+                    '+' => spec.plus = true,
+                    ' ' => spec.space = true,
+                    '#' => spec.alt = true,
+                    _ => break,
+                }
+                next = chars.next();
+            }
+            while let Some(d) = next.and_then(|(_, c)| c.to_digit(10)) {
+                spec.width = add_digit(spec.width, d);
+                next = chars.next();
+            }
+            if let Some((_, '.')) = next {
+                let mut precision = 0;
+                next = chars.next();
+                while let Some(d) = next.and_then(|(_, c)| c.to_digit(10)) {
+                    precision = add_digit(precision, d);
+                    next = chars.next();
+                }
+                spec.precision = Some(precision);
+            }
+            while let Some((_, 'l' | 'h' | 'z' | 'j' | 't' | 'L')) = next {
+                next = chars.next();
+            }
+
+            let (offset, conversion) = next.ok_or(fmt::Error)?;
+            if conversion == '%' {
+                self.write_text("%")?;
+            } else {
+                let arg = args.next().ok_or(fmt::Error)?;
+                self.printf_arg(conversion, &spec, arg)?;
+            }
+            rest = &rest[pos + 1 + offset + conversion.len_utf8()..];
+        }
+        self.write_text(rest)?;
+        match args.next() {
+            Some(_) => Err(fmt::Error),
+            None => Ok(()),
+        }
+    }
+
+    fn printf_arg(&mut self, conversion: char, spec: &Spec, arg: &PrintfArg) -> fmt::Result {
+        let mut body = ArrForm::<64>::new();
+        let mut numeric = true;
+        let integer = matches!(conversion, 'd' | 'i' | 'u' | 'x' | 'X' | 'o');
+        let negative = match (conversion, *arg) {
+            ('d' | 'i' | 'u', PrintfArg::Int(v)) => {
+                write!(body, "{}", v.unsigned_abs())?;
+                v < 0
+            }
+            ('d' | 'i' | 'u', PrintfArg::Uint(v)) => {
+                write!(body, "{}", v)?;
+                false
+            }
+            ('x' | 'X' | 'o', PrintfArg::Int(v)) => {
+                write_radix(&mut body, conversion, v as u64)?;
+                false
+            }
+            ('x' | 'X' | 'o', PrintfArg::Uint(v)) => {
+                write_radix(&mut body, conversion, v)?;
+                false
+            }
+            ('f' | 'F' | 'e' | 'E', PrintfArg::Float(v)) => {
+                let precision = spec.precision.unwrap_or(6);
+                match conversion {
+                    'e' => write!(body, "{:.*e}", precision, v.abs())?,
+                    'E' => write!(body, "{:.*E}", precision, v.abs())?,
+                    _ => write!(body, "{:.*}", precision, v.abs())?,
+                }
+                if let 'e' | 'E' = conversion {
+                    body = c_exponent(&body)?;
+                }
+                v.is_sign_negative()
+            }
+            ('s', PrintfArg::Str(s)) => {
+                let end = match spec.precision {
+                    Some(n) => s.char_indices().nth(n).map_or(s.len(), |(i, _)| i),
+                    None => s.len(),
+                };
+                return self.printf_pad(spec, "", &s[..end], false);
+            }
+            ('c', PrintfArg::Char(c)) => {
+                numeric = false;
+                body.write_char(c)?;
+                false
+            }
+            // C passes characters as int
+            ('c', PrintfArg::Int(v)) => {
+                numeric = false;
+                body.write_char(char_code(u64::try_from(v).map_err(|_| fmt::Error)?)?)?;
+                false
+            }
+            ('c', PrintfArg::Uint(v)) => {
+                numeric = false;
+                body.write_char(char_code(v)?)?;
+                false
+            }
+            _ => return Err(fmt::Error),
+        };
+
+        let mut prefix = "";
+        if numeric {
+            // The precision of integers is the minimum number of digits
+            if let (Some(n), true) = (spec.precision, integer) {
+                let digits = body.len();
+                if digits < n {
+                    let mut padded = ArrForm::<64>::new();
+                    for _ in digits..n {
+                        padded.write_char('0')?;
+                    }
+                    padded.write_str(body.as_str())?;
+                    body = padded;
+                }
+            }
+            let sign = if negative {
+                "-"
+            } else if spec.plus {
+                "+"
+            } else if spec.space {
+                " "
+            } else {
+                ""
+            };
+            prefix = match (spec.alt, conversion) {
+                (true, 'x') => "0x",
+                (true, 'X') => "0X",
+                (true, 'o') => "0",
+                _ => sign,
+            };
+        }
+        // Like C, the 0 flag is ignored for integers with a precision
+        let zero = numeric && !(integer && spec.precision.is_some());
+        self.printf_pad(spec, prefix, body.as_str(), zero)
+    }
+
+    fn printf_pad(&mut self, spec: &Spec, prefix: &str, body: &str, zero: bool) -> fmt::Result {
+        let len = prefix.chars().count() + body.chars().count();
+        let pad = spec.width.saturating_sub(len);
+        if spec.left {
+            self.write_text(prefix)?;
+            self.write_text(body)?;
+            self.printf_fill(' ', pad)
+        } else if spec.zero && zero {
+            self.write_text(prefix)?;
+            self.printf_fill('0', pad)?;
+            self.write_text(body)
+        } else {
+            self.printf_fill(' ', pad)?;
+            self.write_text(prefix)?;
+            self.write_text(body)
+        }
+    }
+
+    fn printf_fill(&mut self, fill: char, count: usize) -> fmt::Result {
+        let mut encoded = [0u8; 4];
+        self.write_repeated(fill.encode_utf8(&mut encoded), count)
+    }
+}
+
+/// Append a decimal digit to a width or precision
+fn add_digit(n: usize, d: u32) -> usize {
+    n.saturating_mul(10).saturating_add(d as usize).min(MAX_WIDTH)
+}
+
+/// Get the character of a C character code
+fn char_code(v: u64) -> Result<char, fmt::Error> {
+    u32::try_from(v).ok().and_then(char::from_u32).ok_or(fmt::Error)
+}
+
+/// Rewrite a Rust exponent like `1.50e3` in the style of C, `1.50e+03`
+fn c_exponent(body: &ArrForm<64>) -> Result<ArrForm<64>, fmt::Error> {
+    let text = body.as_str();
+    let mut c = ArrForm::<64>::new();
+    match text.find(['e', 'E']) {
+        Some(pos) => {
+            let (mantissa, exp) = text.split_at(pos);
+            let (letter, exp) = exp.split_at(1);
+            let exp: i32 = exp.parse().map_err(|_| fmt::Error)?;
+            let sign = if exp < 0 { '-' } else { '+' };
+            write!(c, "{}{}{}{:02}", mantissa, letter, sign, exp.unsigned_abs())?;
+        }
+        // Infinity and NaN have no exponent
+        None => c.write_str(text)?,
+    }
+    Ok(c)
+}
+
+fn write_radix(body: &mut ArrForm<64>, conversion: char, v: u64) -> fmt::Result {
+    match conversion {
+        'x' => write!(body, "{:x}", v),
+        'X' => write!(body, "{:X}", v),
+        _ => write!(body, "{:o}", v),
+    }
+}
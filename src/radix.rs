@@ -0,0 +1,141 @@
+//! Hexadecimal, binary and octal numbers without `core::fmt` padding
+
+use core::fmt;
+
+use crate::{utf8::from_utf8_trusted, FormBuffer, OverflowPolicy, Storage};
+
+const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a number in upper case hexadecimal notation with at least `width` digits
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_hex(0x1bad, 0).unwrap();
+    /// af.push_str(" ").unwrap();
+    /// af.write_hex(0x7ff, 8).unwrap();
+    /// assert_eq!("1BAD 000007FF", af.as_str());
+    /// ```
+    pub fn write_hex(&mut self, v: u32, width: usize) -> fmt::Result {
+        self.write_radix(v, 4, width)
+    }
+
+    /// Append a number in binary notation with at least `width` digits
+    /// ```
+    /// use arrform::{ArrForm, SaturatePolicy};
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_bin(0b101, 8).unwrap();
+    /// assert_eq!("00000101", af.as_str());
+    ///
+    /// let mut af = ArrForm::<8, SaturatePolicy>::new();
+    /// af.write_bin(1, usize::MAX).unwrap();
+    /// assert_eq!("00000000", af.as_str());
+    /// ```
+    pub fn write_bin(&mut self, v: u32, width: usize) -> fmt::Result {
+        self.write_radix(v, 1, width)
+    }
+
+    /// Append a number in octal notation with at least `width` digits
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_oct(0o755, 0).unwrap();
+    /// assert_eq!("755", af.as_str());
+    /// ```
+    pub fn write_oct(&mut self, v: u32, width: usize) -> fmt::Result {
+        self.write_radix(v, 3, width)
+    }
+
+    fn write_radix(&mut self, v: u32, bits: u32, width: usize) -> fmt::Result {
+        let mut buffer = [0u8; 32];
+        let digits = radix_digits(v, bits, &mut buffer);
+        self.write_repeated("0", width.saturating_sub(digits.len()))?;
+        self.write_ascii(digits.as_bytes())
+    }
+}
+
+fn radix_digits(mut v: u32, bits: u32, buffer: &mut [u8; 32]) -> &str {
+    // 32 binary digits of u32::MAX, filled from the end
+    let mut start = buffer.len();
+    for slot in buffer.iter_mut().rev() {
+        start -= 1;
+        *slot = digit(v & ((1 << bits) - 1));
+        v >>= bits;
+        if v == 0 {
+            break;
+        }
+    }
+    // Only ASCII digits have been written
+    from_utf8_trusted(buffer.get(start..).unwrap_or_default())
+}
+
+/// Upper case digit of a value below 16
+pub(crate) fn digit(n: u32) -> u8 {
+    DIGITS.get(n as usize).copied().unwrap_or(b'?')
+}
+
+/// A number shown in upper case hexadecimal notation
+/// ```
+/// use arrform::{arrform, Hex};
+///
+/// assert_eq!("ID 1A2", arrform!(16, "ID {}", Hex(0x1a2)).as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hex(pub u32);
+
+/// A number shown in hexadecimal notation with `W` digits
+/// ```
+/// use arrform::{arrform, HexPad};
+///
+/// assert_eq!("0x0000BEEF", arrform!(16, "0x{}", HexPad::<8>(0xbeef)).as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HexPad<const W: usize>(pub u32);
+
+/// A byte shown in binary notation with 8 digits
+/// ```
+/// use arrform::{arrform, Bin};
+///
+/// assert_eq!("CTRL 00100001", arrform!(16, "CTRL {}", Bin(0x21)).as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bin(pub u8);
+
+/// A number shown in octal notation
+/// ```
+/// use arrform::{arrform, Oct};
+///
+/// assert_eq!("mode 644", arrform!(16, "mode {}", Oct(0o644)).as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Oct(pub u32);
+
+macro_rules! radix_impls {
+    ($(($t:ty, $bits:expr, $width:expr $(, $w:ident)?)),+) => {$(
+        impl$(<const $w: usize>)? fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut buffer = [0u8; 32];
+                let digits = radix_digits(self.0 as u32, $bits, &mut buffer);
+                for _ in digits.len()..$width {
+                    f.write_str("0")?;
+                }
+                f.write_str(digits)
+            }
+        }
+
+        #[cfg(feature = "fast-int")]
+        impl$(<const $w: usize>)? crate::FastArg for $t {
+            fn write_to<S: Storage, P: OverflowPolicy>(
+                &self,
+                af: &mut FormBuffer<S, P>,
+            ) -> fmt::Result {
+                af.write_radix(self.0 as u32, $bits, $width)
+            }
+        }
+    )+};
+}
+
+radix_impls!((Hex, 4, 0), (HexPad<W>, 4, W, W), (Bin, 1, 8), (Oct, 3, 0));
@@ -0,0 +1,128 @@
+//! Ring buffer keeping the most recent formatted lines
+
+use core::fmt;
+
+use crate::utf8::from_utf8_trusted;
+
+/// Keeps the last formatted lines in a ring buffer of `N` bytes
+///
+/// New lines are appended at the end. If the buffer is full, the oldest lines are dropped as a
+/// whole. A single line longer than the buffer keeps only its last bytes.
+/// ```
+/// use arrform::ArrFormRing;
+///
+/// let mut ring = ArrFormRing::<16>::new();
+/// ring.format_line(format_args!("boot")).unwrap();
+/// for t in 21..24 {
+///     ring.format_line(format_args!("temp {}", t)).unwrap();
+/// }
+/// let mut lines = ring.lines();
+/// assert_eq!(Some("temp 22"), lines.next());
+/// assert_eq!(Some("temp 23"), lines.next());
+/// assert_eq!(None, lines.next());
+/// ```
+pub struct ArrFormRing<const N: usize> {
+    buffer: [u8; N],
+    used: usize,
+}
+
+impl<const N: usize> ArrFormRing<N> {
+    /// Creates an empty ring
+    pub const fn new() -> Self {
+        ArrFormRing { buffer: [0; N], used: 0 }
+    }
+
+    /// Format numbers and strings as a new line
+    ///
+    /// The line is terminated even if formatting fails, so the next line stays separate.
+    /// ```
+    /// use arrform::ArrFormRing;
+    /// use core::fmt;
+    ///
+    /// struct Broken;
+    /// impl fmt::Display for Broken {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         f.write_str("part")?;
+    ///         Err(fmt::Error)
+    ///     }
+    /// }
+    ///
+    /// let mut ring = ArrFormRing::<32>::new();
+    /// assert!(ring.format_line(format_args!("{}", Broken)).is_err());
+    /// ring.format_line(format_args!("next")).unwrap();
+    /// assert!(ring.lines().eq(["part", "next"]));
+    /// ```
+    pub fn format_line(&mut self, args: fmt::Arguments) -> fmt::Result {
+        let result = fmt::write(self, args);
+        self.push_bytes(b"\n");
+        result
+    }
+
+    /// Iterate over the retained lines, oldest first
+    pub fn lines(&self) -> core::str::Lines<'_> {
+        let content = self.buffer.get(..self.used).unwrap_or_default();
+        // An overlong line may have been cut inside a character
+        let skip = content.iter().take_while(|&&b| b & 0xc0 == 0x80).count();
+        // Only complete strings have been written, only the start may be cut
+        from_utf8_trusted(content.get(skip..).unwrap_or_default()).lines()
+    }
+
+    /// Remove all lines
+    pub fn clear(&mut self) {
+        self.used = 0;
+    }
+
+    /// Get the number of retained bytes
+    pub fn len(&self) -> usize {
+        self.used
+    }
+
+    /// Check if the ring is empty
+    pub fn is_empty(&self) -> bool {
+        self.used == 0
+    }
+
+    /// Get the capacity of the ring in bytes
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        // Lines are added one by one, so a full ring drops just enough old lines
+        for line in bytes.split_inclusive(|&b| b == b'\n') {
+            // Only the last bytes of an overlong line can be kept
+            let line = line.get(line.len().saturating_sub(N)..).unwrap_or_default();
+            self.make_room(line.len());
+            if let Some(slot) = self.buffer.get_mut(self.used..self.used + line.len()) {
+                slot.copy_from_slice(line);
+                self.used += line.len();
+            }
+        }
+    }
+
+    /// Drop the oldest lines and move the rest to the front, so the content stays contiguous
+    fn make_room(&mut self, len: usize) {
+        let mut dropped = 0;
+        while self.used - dropped + len > N {
+            let excess = self.used - dropped + len - N;
+            let rest = self.buffer.get(dropped..self.used).unwrap_or_default();
+            // Without a complete line only the start of the current line is cut
+            dropped += rest.iter().position(|&b| b == b'\n').map_or(excess, |i| i + 1);
+        }
+        self.buffer.copy_within(dropped..self.used, 0);
+        self.used -= dropped;
+    }
+}
+
+impl<const N: usize> Default for ArrFormRing<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for ArrFormRing<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_bytes(s.as_bytes());
+        Ok(())
+    }
+}
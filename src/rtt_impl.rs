@@ -0,0 +1,42 @@
+//! Output to an RTT up channel of `rtt-target`
+
+use rtt_target::UpChannel;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Write the text to an RTT up channel in one call
+    ///
+    /// Returns the number of bytes accepted by the channel. If the host isn't reading, this is 
+    /// less than [ArrForm::len](crate::ArrForm::len), depending on the channel mode: 
+    /// `NoBlockSkip` drops the whole text, `NoBlockTrim` sends as much as fits and 
+    /// `BlockIfFull` waits for the host.
+    pub fn write_rtt(&self, channel: &mut UpChannel) -> usize {
+        channel.write(self.as_bytes())
+    }
+}
+
+/// Format numbers and strings and push them to an RTT up channel
+///
+/// The text is formatted into a buffer of the given size first and then written to the channel
+/// in one call, see [ArrForm::write_rtt](crate::ArrForm::write_rtt). Returns the number of
+/// bytes accepted by the channel or [ArrFormError](crate::ArrFormError) if the text does not
+/// fit into the buffer.
+/// ```
+/// use arrform::rtt_arrform;
+/// use rtt_target::{rtt_init, ChannelMode};
+///
+/// let channels = rtt_init! {
+///     up: { 0: { size: 256, mode: ChannelMode::NoBlockSkip, name: "Terminal" } }
+/// };
+/// let mut terminal = channels.up.0;
+/// let sent = rtt_arrform!(terminal, 32, "T={:.1}\r\n", 21.56).unwrap();
+/// assert_eq!(8, sent);
+/// ```
+#[macro_export]
+macro_rules! rtt_arrform {
+    ($channel:expr, $size:expr, $($arg:tt)*) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+        af.format_detailed(format_args!($($arg)*)).map(|_| af.write_rtt(&mut $channel))
+    }}
+}
@@ -0,0 +1,122 @@
+//! Several messages back to back in one buffer
+
+use core::{fmt, slice};
+
+use crate::ArrForm;
+
+/// Buffer of `N` bytes holding up to `M` messages back to back
+///
+/// Each message is formatted as a segment with [SegmentedForm::push]. The segments can be read
+/// one by one, or all together as one block, e.g. to send queued log lines with a single DMA
+/// transfer or radio packet.
+/// ```
+/// use arrform::SegmentedForm;
+///
+/// let mut batch = SegmentedForm::<32, 4>::new();
+/// batch.push(format_args!("T={}\n", 21)).unwrap();
+/// batch.push(format_args!("H={}%\n", 45)).unwrap();
+/// assert!(batch.push(format_args!("error {}: {}\n", 7, "sensor timeout")).is_err());
+/// batch.push(format_args!("P={}\n", 1013)).unwrap();
+///
+/// let mut segments = batch.segments();
+/// assert_eq!(Some("T=21\n"), segments.next());
+/// assert_eq!(Some("H=45%\n"), segments.next());
+/// assert_eq!(Some("P=1013\n"), segments.next());
+/// assert_eq!(None, segments.next());
+/// assert_eq!(b"T=21\nH=45%\nP=1013\n", batch.as_bytes());
+/// ```
+#[derive(Clone)]
+pub struct SegmentedForm<const N: usize, const M: usize> {
+    form: ArrForm<N>,
+    ends: [usize; M],
+    count: usize,
+}
+
+impl<const N: usize, const M: usize> SegmentedForm<N, M> {
+    /// Creates an empty buffer
+    pub const fn new() -> Self {
+        SegmentedForm { form: ArrForm::new(), ends: [0; M], count: 0 }
+    }
+
+    /// Format numbers and strings as a new segment behind the previous ones
+    ///
+    /// An error is returned and nothing is stored, if the text does not fit into the buffer or
+    /// all `M` segments are in use.
+    pub fn push(&mut self, args: fmt::Arguments) -> fmt::Result {
+        let end = self.ends.get_mut(self.count).ok_or(fmt::Error)?;
+        let mark = self.form.checkpoint();
+        if let Err(e) = self.form.append(args) {
+            self.form.rollback(mark);
+            return Err(e);
+        }
+        *end = self.form.len();
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Get a segment by its index, starting with 0 for the oldest one
+    pub fn segment(&self, index: usize) -> Option<&str> {
+        self.segments().nth(index)
+    }
+
+    /// Iterate over the segments, oldest first
+    pub fn segments(&self) -> Segments<'_> {
+        let ends = self.ends.get(..self.count).unwrap_or_default();
+        Segments { text: self.form.as_str(), ends: ends.iter(), start: 0 }
+    }
+
+    /// Get all segments together as str
+    pub fn as_str(&self) -> &str {
+        self.form.as_str()
+    }
+
+    /// Get all segments together as bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        self.form.as_bytes()
+    }
+
+    /// Get the number of segments
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if there are no segments
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get the number of bytes, which are still free
+    pub fn remaining(&self) -> usize {
+        self.form.remaining()
+    }
+
+    /// Remove all segments, e.g. after they have been sent
+    pub fn clear(&mut self) {
+        self.form.clear();
+        self.count = 0;
+    }
+}
+
+impl<const N: usize, const M: usize> Default for SegmentedForm<N, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the segments of a [SegmentedForm]
+pub struct Segments<'a> {
+    text: &'a str,
+    ends: slice::Iter<'a, usize>,
+    start: usize,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let end = *self.ends.next()?;
+        let segment = self.text.get(self.start..end);
+        self.start = end;
+        segment
+    }
+}
@@ -0,0 +1,53 @@
+//! Support for the `serde` serialization framework
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Serializes the content as str
+impl<S: Storage, P: OverflowPolicy> Serialize for FormBuffer<S, P> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserializes a str into the buffer, failing if it does not fit
+/// ```
+/// use arrform::ArrForm;
+/// use serde::{de::{value::{Error, StrDeserializer}, IntoDeserializer}, Deserialize};
+///
+/// let de: StrDeserializer<Error> = "ready".into_deserializer();
+/// assert_eq!("ready", ArrForm::<8>::deserialize(de).unwrap());
+///
+/// let de: StrDeserializer<Error> = "not ready".into_deserializer();
+/// assert!(ArrForm::<8>::deserialize(de).is_err());
+/// ```
+impl<'de, const BUF_SIZE: usize, P: OverflowPolicy> Deserialize<'de>
+    for FormBuffer<[u8; BUF_SIZE], P>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ArrFormVisitor(PhantomData))
+    }
+}
+
+struct ArrFormVisitor<const BUF_SIZE: usize, P>(PhantomData<P>);
+
+impl<const BUF_SIZE: usize, P: OverflowPolicy> de::Visitor<'_> for ArrFormVisitor<BUF_SIZE, P> {
+    type Value = FormBuffer<[u8; BUF_SIZE], P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string of at most {} bytes", BUF_SIZE)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        FormBuffer::with_prefix(s).map_err(|_| E::invalid_length(s.len(), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, b: &[u8]) -> Result<Self::Value, E> {
+        let s = core::str::from_utf8(b)
+            .map_err(|_| E::invalid_value(de::Unexpected::Bytes(b), &self))?;
+        self.visit_str(s)
+    }
+}
@@ -0,0 +1,116 @@
+//! Formatting straight to a serial port
+
+use core::fmt;
+
+use embedded_hal::serial;
+
+use crate::ArrForm;
+
+/// Formats text directly to a serial port, using a small staging buffer
+///
+/// The staging buffer of `N` bytes is sent whenever it is full and on [FmtSink::flush]. 
+/// Sending blocks until the serial port accepted all bytes.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::FmtSink;
+///
+/// struct Uart(Vec<u8>);
+///
+/// impl embedded_hal::serial::Write<u8> for Uart {
+///     type Error = ();
+///     fn write(&mut self, word: u8) -> nb::Result<(), ()> {
+///         Ok(self.0.push(word))
+///     }
+///     fn flush(&mut self) -> nb::Result<(), ()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let mut uart = Uart(Vec::new());
+/// let mut sink = FmtSink::<_, 4>::new(&mut uart);
+/// write!(sink, "V={}mV\r\n", 3300).unwrap();
+/// sink.flush().unwrap();
+/// assert_eq!(b"V=3300mV\r\n", &uart.0[..]);
+/// ```
+pub struct FmtSink<'a, W: serial::Write<u8>, const N: usize = 32> {
+    serial: &'a mut W,
+    staging: ArrForm<N>,
+}
+
+impl<'a, W: serial::Write<u8>, const N: usize> FmtSink<'a, W, N> {
+    /// Creates new sink for the given serial port
+    pub fn new(serial: &'a mut W) -> Self {
+        FmtSink { serial, staging: ArrForm::new() }
+    }
+
+    /// Send the content of the staging buffer and flush the serial port
+    pub fn flush(&mut self) -> fmt::Result {
+        self.send_staging()?;
+        nb::block!(self.serial.flush()).map_err(|_| fmt::Error)
+    }
+
+    fn send_staging(&mut self) -> fmt::Result {
+        for byte in self.staging.as_bytes() {
+            nb::block!(self.serial.write(*byte)).map_err(|_| fmt::Error)?;
+        }
+        self.staging.clear();
+        Ok(())
+    }
+
+    fn send(&mut self, byte: u8) -> fmt::Result {
+        nb::block!(self.serial.write(byte)).map_err(|_| fmt::Error)
+    }
+}
+
+impl<W: serial::Write<u8>, const N: usize> fmt::Write for FmtSink<'_, W, N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Without staging buffer the bytes are sent one by one
+        if N == 0 {
+            return s.bytes().try_for_each(|byte| self.send(byte));
+        }
+
+        let mut bytes = s.as_bytes();
+        while !bytes.is_empty() {
+            if self.staging.remaining() == 0 {
+                self.send_staging()?;
+            }
+            let len = bytes.len().min(self.staging.remaining());
+            let (head, tail) = bytes.split_at_checked(len).unwrap_or((bytes, &[]));
+            self.staging.write_bytes(head)?;
+            bytes = tail;
+        }
+        Ok(())
+    }
+}
+
+/// A macro to format text straight to a serial port
+///
+/// The text is formatted through a [FmtSink] and flushed at the end. The macro evaluates to 
+/// `core::fmt::Result`.
+/// ```
+/// use arrform::serform;
+///
+/// struct Uart(Vec<u8>);
+///
+/// impl embedded_hal::serial::Write<u8> for Uart {
+///     type Error = ();
+///     fn write(&mut self, word: u8) -> nb::Result<(), ()> {
+///         Ok(self.0.push(word))
+///     }
+///     fn flush(&mut self) -> nb::Result<(), ()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let mut uart = Uart(Vec::new());
+/// serform!(uart, "T={:.1}\r\n", 21.56).unwrap();
+/// assert_eq!(b"T=21.6\r\n", &uart.0[..]);
+/// ```
+#[macro_export]
+macro_rules! serform {
+    ($serial:expr, $($arg:tt)*) => {{
+        let mut sink = $crate::FmtSink::<_>::new(&mut $serial);
+        ::core::fmt::Write::write_fmt(&mut sink, format_args!($($arg)*))
+            .and_then(|_| sink.flush())
+    }}
+}
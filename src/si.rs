@@ -0,0 +1,191 @@
+//! Numbers with SI and binary prefixes
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+const PREFIXES: [&str; 11] = ["f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P"];
+const UNITY: usize = 5;
+const BINARY_UNITS: [&str; 7] = [" B", " KiB", " MiB", " GiB", " TiB", " PiB", " EiB"];
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a value with SI prefix and unit, using 3 significant digits
+    ///
+    /// Trailing zeros of the fraction are omitted.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_si(0.0000047, "F").unwrap();
+    /// assert_eq!("4.7µF", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_si(3_300_000.0, "Hz").unwrap();
+    /// assert_eq!("3.3MHz", af.as_str());
+    /// ```
+    pub fn write_si(&mut self, value: f32, unit: &str) -> fmt::Result {
+        self.write_si_digits(value, 3, unit)
+    }
+
+    /// Append a value with SI prefix and unit, using `digits` significant digits
+    ///
+    /// The number of digits is limited to 1 to 7, which is what an `f32` can hold. Values beyond 
+    /// the prefixes from `f` to `P` are shown with the smallest or largest prefix.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_si_digits(-1234.5, 4, "V").unwrap();
+    /// assert_eq!("-1.235kV", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_si_digits(999.7, 3, "Ω").unwrap();
+    /// assert_eq!("1kΩ", af.as_str());
+    ///
+    /// let mut af = ArrForm::<4>::new();
+    /// assert!(af.write_si_digits(4.7e-6, 2, "F").is_err());
+    /// assert_eq!("4.7", af.as_str());
+    /// ```
+    pub fn write_si_digits(&mut self, value: f32, digits: u8, unit: &str) -> fmt::Result {
+        self.write_si_digits_sep(value, digits, unit, '.')
+    }
+
+    /// Append a value like [ArrForm::write_si_digits] with another decimal separator
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_si_digits_sep(0.0000047, 3, "F", ',').unwrap();
+    /// assert_eq!("4,7µF", af.as_str());
+    /// ```
+    ///
+    /// [ArrForm::write_si_digits]: crate::FormBuffer::write_si_digits
+    pub fn write_si_digits_sep(
+        &mut self,
+        value: f32,
+        digits: u8,
+        unit: &str,
+        separator: char,
+    ) -> fmt::Result {
+        if value.is_nan() {
+            return self.write_ascii(b"NaN");
+        }
+        if value.is_sign_negative() && value != 0.0 {
+            self.write_ascii(b"-")?;
+        }
+        let mut v = value.abs();
+        if v.is_infinite() {
+            return self.write_ascii(b"inf");
+        }
+
+        let mut index = UNITY;
+        if v != 0.0 {
+            while v >= 1000.0 && index < PREFIXES.len() - 1 {
+                v /= 1000.0;
+                index += 1;
+            }
+            while v < 1.0 && index > 0 {
+                v *= 1000.0;
+                index -= 1;
+            }
+        }
+
+        let digits = digits.clamp(1, 7) as u32;
+        let (mut n, mut decimals) = round_significant(v, digits);
+        // Rounding may carry over to the next prefix, like 999.7 to 1k
+        if n >= 1000 * 10u32.pow(decimals) && index < PREFIXES.len() - 1 {
+            index += 1;
+            (n, decimals) = round_significant(v / 1000.0, digits);
+        }
+
+        let scale = 10u32.pow(decimals);
+        let mut frac = n % scale;
+        self.put_u32(n / scale)?;
+        if frac != 0 {
+            while frac % 10 == 0 {
+                frac /= 10;
+                decimals -= 1;
+            }
+            self.write_decimal_separator(separator)?;
+            let frac_len = frac.ilog10() + 1;
+            for _ in frac_len..decimals {
+                self.write_ascii(b"0")?;
+            }
+            self.put_u32(frac)?;
+        }
+        self.write_text(PREFIXES.get(index).unwrap_or(&""))?;
+        self.write_text(unit)
+    }
+
+    /// Append a byte count with binary prefix
+    ///
+    /// Values below 10 units get one decimal, larger values are rounded to whole units.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_bytes_human(1536).unwrap();
+    /// assert_eq!("1.5 KiB", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_bytes_human(12 * 1024 * 1024 + 1000).unwrap();
+    /// assert_eq!("12 MiB", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_bytes_human(512).unwrap();
+    /// assert_eq!("512 B", af.as_str());
+    /// ```
+    pub fn write_bytes_human(&mut self, n: u64) -> fmt::Result {
+        self.write_bytes_human_sep(n, '.')
+    }
+
+    /// Append a byte count like [ArrForm::write_bytes_human] with another decimal separator
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_bytes_human_sep(1536, ',').unwrap();
+    /// assert_eq!("1,5 KiB", af.as_str());
+    /// ```
+    ///
+    /// [ArrForm::write_bytes_human]: crate::FormBuffer::write_bytes_human
+    pub fn write_bytes_human_sep(&mut self, n: u64, separator: char) -> fmt::Result {
+        let unit = ((u64::BITS - n.leading_zeros()).saturating_sub(1) / 10) as usize;
+        let shift = 10 * unit as u32;
+        let mut whole = n >> shift;
+        let rest = n - (whole << shift);
+        let half = (1u64 << shift) >> 1;
+
+        if unit > 0 && whole < 10 {
+            let mut tenths = (rest * 10 + half) >> shift;
+            if tenths == 10 {
+                whole += 1;
+                tenths = 0;
+            }
+            self.put_u32(whole as u32)?;
+            if tenths > 0 {
+                self.write_decimal_separator(separator)?;
+                self.put_u32(tenths as u32)?;
+            }
+        } else {
+            if unit > 0 && rest >= half {
+                whole += 1;
+            }
+            self.put_u32(whole as u32)?;
+        }
+        self.write_ascii(BINARY_UNITS.get(unit).unwrap_or(&"").as_bytes())
+    }
+}
+
+/// Round to `digits` significant digits, returning the scaled value and the decimals
+fn round_significant(v: f32, digits: u32) -> (u32, u32) {
+    let int_digits = if v >= 100.0 {
+        3
+    } else if v >= 10.0 {
+        2
+    } else {
+        1
+    };
+    let decimals = digits.saturating_sub(int_digits);
+    ((v * 10u32.pow(decimals) as f32 + 0.5) as u32, decimals)
+}
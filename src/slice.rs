@@ -0,0 +1,106 @@
+//! Formatting into a byte slice provided by the caller
+
+use core::{fmt, mem::MaybeUninit};
+
+use crate::{
+    utf8::{from_utf8_trusted, valid_prefix},
+    ErrorPolicy, FormBuffer, OverflowPolicy, Storage,
+};
+
+/// Generates formatted text in a byte slice provided by the caller
+///
+/// Works like [ArrForm](crate::ArrForm), but the buffer is borrowed instead of owned. This 
+/// allows formatting directly into memory, which lives in a special place, e.g. a DMA buffer.
+/// ```
+/// use arrform::ArrFormRef;
+///
+/// let mut dma_buf = [0u8; 32];
+/// let mut af = ArrFormRef::new(&mut dma_buf);
+/// af.format(format_args!("int {}", 42)).unwrap();
+/// af.append(format_args!(", float {:.1}", 1.25)).unwrap();
+/// assert_eq!("int 42, float 1.2", af.as_str());
+/// ```
+pub type ArrFormRef<'a, P = ErrorPolicy> = FormBuffer<&'a mut [u8], P>;
+
+impl<'a> FormBuffer<&'a mut [u8]> {
+    /// Creates new writer over the given slice
+    ///
+    /// Use [FormBuffer::from_storage] to select another [OverflowPolicy].
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self::from_storage(buffer)
+    }
+
+    /// Creates new writer over uninitialized memory provided by the caller
+    ///
+    /// The memory can live wherever the caller wants, e.g. in CCM or DMA-capable RAM. It is 
+    /// zeroed first, because reading uninitialized integers is undefined behaviour.
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use arrform::ArrFormRef;
+    ///
+    /// static mut DMA_BUF: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
+    ///
+    /// let mem = unsafe { &mut *core::ptr::addr_of_mut!(DMA_BUF) };
+    /// let mut af = ArrFormRef::new_in(mem);
+    /// af.format(format_args!("int {}", 42)).unwrap();
+    /// assert_eq!("int 42", af.as_str());
+    /// assert_eq!(32, af.capacity());
+    /// ```
+    pub fn new_in<const N: usize>(buffer: &'a mut MaybeUninit<[u8; N]>) -> Self {
+        Self::from_storage(buffer.write([0; N]))
+    }
+}
+
+impl<'a, P: OverflowPolicy> FormBuffer<&'a mut [u8], P> {
+    /// Consume the writer and return the result as str, borrowing the original slice
+    pub fn into_str(self) -> &'a str {
+        let filled = self.buffer.get(..self.used).unwrap_or_default();
+        if self.raw {
+            return valid_prefix(filled);
+        }
+        // We are really sure, that the buffer contains only valid utf8 characters
+        from_utf8_trusted(filled)
+    }
+}
+
+/// Format numbers and strings into a byte slice owned by the caller
+///
+/// This is the simplest entry point when the buffer is externally owned. On success the 
+/// formatted text is returned as a str borrowing the buffer. An error is returned if the 
+/// buffer is too small.
+/// ```
+/// use arrform::format_into;
+///
+/// let mut buf = [0u8; 16];
+/// let s = format_into(&mut buf, format_args!("int {}", 42)).unwrap();
+/// assert_eq!("int 42", s);
+///
+/// let mut buf = [0u8; 4];
+/// assert!(format_into(&mut buf, format_args!("int {}", 42)).is_err());
+/// ```
+pub fn format_into<'a>(buf: &'a mut [u8], args: fmt::Arguments) -> Result<&'a str, fmt::Error> {
+    let mut af = ArrFormRef::new(buf);
+    af.format(args)?;
+    Ok(af.into_str())
+}
+
+/// Existing memory, which [arrform_into!](crate::arrform_into!) formats into
+///
+/// Implemented for the buffers and for byte slices.
+pub trait FormatTarget {
+    /// Format numbers and strings, replacing the previous content
+    fn format_target(&mut self, args: fmt::Arguments) -> Result<&str, fmt::Error>;
+}
+
+impl<S: Storage, P: OverflowPolicy> FormatTarget for FormBuffer<S, P> {
+    fn format_target(&mut self, args: fmt::Arguments) -> Result<&str, fmt::Error> {
+        self.format(args)?;
+        Ok(self.as_str())
+    }
+}
+
+impl FormatTarget for [u8] {
+    fn format_target(&mut self, args: fmt::Arguments) -> Result<&str, fmt::Error> {
+        format_into(self, args)
+    }
+}
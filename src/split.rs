@@ -0,0 +1,100 @@
+//! Formatting into the unused tail of a buffer
+
+use core::{fmt, ops::Deref};
+
+use crate::{ArrFormRef, FormBuffer, OverflowPolicy, Storage};
+
+/// Writer over the unused tail of a buffer, see [ArrForm::split_remaining]
+///
+/// The writer appends like an [ArrFormRef] starting at the end of the content and can be read
+/// like one. When the writer is dropped, the text written into it becomes part of the content
+/// of the buffer.
+///
+/// [ArrForm::split_remaining]: crate::FormBuffer::split_remaining
+pub struct Remaining<'a, P: OverflowPolicy> {
+    tail: ArrFormRef<'a, P>,
+    used: &'a mut usize,
+    dropped: &'a mut usize,
+    raw: &'a mut bool,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a writer over the free part of the buffer
+    ///
+    /// This allows a protocol layer to write a header, hand the rest of the buffer to the
+    /// payload layer and patch the header afterwards, e.g. with a length field.
+    /// ```
+    /// use core::fmt::Write;
+    /// use arrform::ArrForm;
+    ///
+    /// fn payload(w: &mut impl Write) -> core::fmt::Result {
+    ///     write!(w, "T={:.1};H={}", 21.56, 45)
+    /// }
+    ///
+    /// let mut frame = ArrForm::<32>::new();
+    /// frame.push_str("LEN=00|").unwrap();
+    /// let len = {
+    ///     let mut body = frame.split_remaining();
+    ///     payload(&mut body).unwrap();
+    ///     body.len()
+    /// };
+    /// frame.format_at(4, format_args!("{:02}", len)).unwrap();
+    /// assert_eq!("LEN=11|T=21.6;H=45", frame.as_str());
+    /// ```
+    ///
+    /// The writer handles an overflow like the buffer:
+    /// ```
+    /// use core::fmt::Write;
+    /// use arrform::{ArrForm, OverflowMode, RuntimePolicy};
+    ///
+    /// let mut af = ArrForm::<4, RuntimePolicy>::new();
+    /// af.set_overflow_mode(OverflowMode::Truncate);
+    /// assert!(af.split_remaining().write_str("123456").is_ok());
+    /// assert_eq!("1234", af.as_str());
+    /// ```
+    pub fn split_remaining(&mut self) -> Remaining<'_, P> {
+        let tail = self.buffer.as_mut_slice().get_mut(self.used..).unwrap_or_default();
+        let mut tail = FormBuffer::from_storage(tail);
+        tail.state = self.state;
+        Remaining {
+            tail,
+            used: &mut self.used,
+            dropped: &mut self.dropped,
+            raw: &mut self.raw,
+        }
+    }
+}
+
+impl<'a, P: OverflowPolicy> Deref for Remaining<'a, P> {
+    type Target = ArrFormRef<'a, P>;
+
+    fn deref(&self) -> &ArrFormRef<'a, P> {
+        &self.tail
+    }
+}
+
+impl<P: OverflowPolicy> Remaining<'_, P> {
+    /// Format numbers and strings behind the text written so far
+    pub fn append(&mut self, args: fmt::Arguments) -> fmt::Result {
+        self.tail.append(args)
+    }
+
+    /// Append raw bytes, see [ArrForm::write_bytes](crate::FormBuffer::write_bytes)
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.tail.write_bytes(bytes)
+    }
+}
+
+impl<P: OverflowPolicy> fmt::Write for Remaining<'_, P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.tail.write_str(s)
+    }
+}
+
+impl<P: OverflowPolicy> Drop for Remaining<'_, P> {
+    fn drop(&mut self) {
+        *self.used += self.tail.used;
+        *self.dropped += self.tail.dropped;
+        *self.raw |= self.tail.raw;
+    }
+}
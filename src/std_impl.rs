@@ -0,0 +1,49 @@
+//! Support for `std::io` on hosted targets
+
+use std::io;
+
+use crate::{utf8::text_prefix, ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+/// Allows the buffer to be the target of `std::io` writers
+///
+/// Writes are partial if the buffer is nearly full, the bytes are accepted up to the last
+/// complete character. Once it is full, `write` returns `Ok(0)`, so `write_all` fails with
+/// [io::ErrorKind::WriteZero]. Bytes, which are not valid UTF-8, fail with
+/// [io::ErrorKind::InvalidData].
+/// ```
+/// use std::io::{ErrorKind, Write};
+/// use arrform::ArrForm;
+///
+/// let mut af = ArrForm::<8>::new();
+/// write!(af, "int {}", 42).unwrap();
+/// assert_eq!("int 42", af.as_str());
+/// assert!(af.write_all(b"123").is_err());
+///
+/// let mut af = ArrForm::<3>::new();
+/// assert_eq!(2, af.write("ab°C".as_bytes()).unwrap());
+/// assert_eq!(0, af.write("°C".as_bytes()).unwrap());
+/// assert_eq!(ErrorKind::InvalidData, af.write(&[0xff]).unwrap_err().kind());
+/// assert_eq!("ab", af.as_str());
+/// ```
+impl<S: Storage, P: OverflowPolicy> io::Write for FormBuffer<S, P> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let text = text_prefix(buf, self.remaining())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.write_text(text).map_err(|_| io::Error::from(io::ErrorKind::WriteZero))?;
+        Ok(text.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl From<ArrFormError> for io::Error {
+    fn from(err: ArrFormError) -> Self {
+        let kind = match err {
+            ArrFormError::Overflow { .. } => io::ErrorKind::WriteZero,
+            ArrFormError::Fmt => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, err)
+    }
+}
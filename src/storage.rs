@@ -0,0 +1,115 @@
+//! Backing memory of the formatting buffers
+
+/// Memory a [FormBuffer](crate::FormBuffer) formats into
+///
+/// Implemented for owned arrays, borrowed slices and borrowed arrays (e.g. `&'static mut` 
+/// buffers). Own buffer types only need to implement this trait to get the complete 
+/// formatting functionality.
+///
+/// The trait is safe to implement, so the crate cannot rely on `as_slice` returning the bytes
+/// written through `as_mut_slice`. The content of storages from other crates is validated as
+/// UTF-8 each time it is read as str, the storages of the crate skip this check.
+/// ```
+/// use arrform::{FormBuffer, Storage};
+///
+/// struct DmaBuffer([u8; 16]);
+///
+/// impl Storage for DmaBuffer {
+///     fn as_slice(&self) -> &[u8] {
+///         &self.0
+///     }
+///     fn as_mut_slice(&mut self) -> &mut [u8] {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut af: FormBuffer<DmaBuffer> = FormBuffer::from_storage(DmaBuffer([0; 16]));
+/// af.format(format_args!("int {}", 42)).unwrap();
+/// assert_eq!("int 42", af.as_str());
+/// ```
+///
+/// A storage returning other memory for reading only yields valid text:
+/// ```
+/// use arrform::{FormBuffer, Storage};
+///
+/// struct Bogus([u8; 8]);
+///
+/// impl Storage for Bogus {
+///     fn as_slice(&self) -> &[u8] {
+///         &[0xff; 8]
+///     }
+///     fn as_mut_slice(&mut self) -> &mut [u8] {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut af: FormBuffer<Bogus> = FormBuffer::from_storage(Bogus([0; 8]));
+/// af.format(format_args!("{}", 42)).unwrap();
+/// assert_eq!("", af.as_str());
+/// ```
+pub trait Storage {
+    /// Get the complete memory as slice
+    fn as_slice(&self) -> &[u8];
+
+    /// Get the complete memory as mutable slice
+    fn as_mut_slice(&mut self) -> &mut [u8];
+
+    /// Check if both methods return the same memory, so written text needs no validation
+    ///
+    /// Only the storages of the crate can override this, because the token cannot be named
+    /// outside of it.
+    #[doc(hidden)]
+    fn is_trusted(_: sealed::Token) -> bool {
+        false
+    }
+}
+
+mod sealed {
+    /// Proof for [Storage::is_trusted](super::Storage::is_trusted), which only the crate can
+    /// name
+    pub struct Token;
+}
+
+pub(crate) use sealed::Token;
+
+impl<const N: usize> Storage for [u8; N] {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn is_trusted(_: Token) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> Storage for &mut [u8; N] {
+    fn as_slice(&self) -> &[u8] {
+        &self[..]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self[..]
+    }
+
+    fn is_trusted(_: Token) -> bool {
+        true
+    }
+}
+
+impl Storage for &mut [u8] {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn is_trusted(_: Token) -> bool {
+        true
+    }
+}
@@ -0,0 +1,94 @@
+//! Formatting arbitrarily long output through a fixed buffer
+
+use core::fmt;
+
+use crate::ArrForm;
+
+/// Formats arbitrarily long output through a buffer of `N` bytes
+///
+/// Whenever the buffer is full, its content is handed to the flush closure. Call
+/// [StreamForm::flush] at the end to pass on the rest. If the closure fails, formatting stops
+/// with an error and the closure's error can be fetched with [StreamForm::take_error].
+/// ```
+/// use arrform::StreamForm;
+///
+/// let mut sent = Vec::new();
+/// let mut stream = StreamForm::<8, _, ()>::new(|chunk: &[u8]| {
+///     sent.push(chunk.len());
+///     Ok(())
+/// });
+/// for value in 0..5 {
+///     stream.format(format_args!("{{\"v\":{}}}", value)).unwrap();
+/// }
+/// stream.flush().unwrap();
+/// drop(stream);
+/// assert_eq!(vec![8, 8, 8, 8, 3], sent);
+/// ```
+pub struct StreamForm<const N: usize, F, E>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    buffer: ArrForm<N>,
+    flush: F,
+    error: Option<E>,
+}
+
+impl<const N: usize, F, E> StreamForm<N, F, E>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    /// Creates new stream, which passes full buffers to `flush`
+    pub fn new(flush: F) -> Self {
+        StreamForm { buffer: ArrForm::new(), flush, error: None }
+    }
+
+    /// Format numbers and strings into the stream
+    pub fn format(&mut self, args: fmt::Arguments) -> fmt::Result {
+        fmt::write(self, args)
+    }
+
+    /// Pass the content of the buffer to the flush closure
+    pub fn flush(&mut self) -> Result<(), E> {
+        if !self.buffer.is_empty() {
+            (self.flush)(self.buffer.as_bytes())?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+
+    /// Get the error of the flush closure, which stopped formatting
+    pub fn take_error(&mut self) -> Option<E> {
+        self.error.take()
+    }
+}
+
+impl<const N: usize, F, E> fmt::Write for StreamForm<N, F, E>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut bytes = s.as_bytes();
+
+        // Without buffer the bytes are passed on directly
+        if N == 0 {
+            return (self.flush)(bytes).map_err(|err| {
+                self.error = Some(err);
+                fmt::Error
+            });
+        }
+
+        while !bytes.is_empty() {
+            if self.buffer.remaining() == 0 {
+                if let Err(err) = self.flush() {
+                    self.error = Some(err);
+                    return Err(fmt::Error);
+                }
+            }
+            let len = bytes.len().min(self.buffer.remaining());
+            let (head, tail) = bytes.split_at_checked(len).unwrap_or((bytes, &[]));
+            self.buffer.write_bytes(head)?;
+            bytes = tail;
+        }
+        Ok(())
+    }
+}
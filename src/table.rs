@@ -0,0 +1,137 @@
+//! Tables with aligned columns
+
+use core::fmt;
+
+use crate::{Align, FormBuffer, OverflowPolicy, Storage};
+
+/// Width and alignment of a table column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Column {
+    width: usize,
+    align: Align,
+    cells: bool,
+}
+
+impl Column {
+    /// Column of `width` bytes with left aligned text
+    pub const fn left(width: usize) -> Self {
+        Column { width, align: Align::Left, cells: false }
+    }
+
+    /// Column of `width` bytes with right aligned text, e.g. for numbers
+    pub const fn right(width: usize) -> Self {
+        Column { width, align: Align::Right, cells: false }
+    }
+
+    /// Count the width in display cells instead of bytes, see [ArrForm::write_field_cells]
+    ///
+    /// Use this for columns with user-provided names, which may contain wide East Asian or
+    /// combining characters.
+    ///
+    /// [ArrForm::write_field_cells]: crate::FormBuffer::write_field_cells
+    pub const fn cells(self) -> Self {
+        Column { cells: true, ..self }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a builder, which appends table rows with the given columns to this buffer
+    ///
+    /// `separator` is written between the cells of a row.
+    pub fn table<'a>(
+        &'a mut self,
+        columns: &'a [Column],
+        separator: &'a str,
+    ) -> TableForm<'a, S, P> {
+        TableForm { buffer: self, columns, separator, cell: None, result: Ok(()) }
+    }
+}
+
+/// Builder for tables with aligned columns in a buffer
+///
+/// Each cell is padded with spaces to the width of its column, longer text is clipped without
+/// an error. Rows are terminated with `"\r\n"`. The first error, like a full buffer, is kept
+/// and returned by [TableForm::finish], later calls do nothing. A cell beyond the last column
+/// is an error.
+/// ```
+/// use arrform::{ArrForm, Column};
+///
+/// let columns = [Column::left(6), Column::right(5), Column::right(6)];
+/// let mut af = ArrForm::<96>::new();
+/// let mut table = af.table(&columns, " ");
+/// table.row().cell(format_args!("task")).cell(format_args!("stack")).cell(format_args!("load"));
+/// for (name, stack, load) in [("idle", 128, 91.3), ("net", 1536, 6.5)] {
+///     table
+///         .row()
+///         .cell(format_args!("{}", name))
+///         .cell(format_args!("{}", stack))
+///         .cell(format_args!("{:.1}%", load));
+/// }
+/// table.finish().unwrap();
+/// assert_eq!(
+///     "task   stack   load\r\nidle     128  91.3%\r\nnet     1536   6.5%\r\n",
+///     af.as_str()
+/// );
+///
+/// let columns = [Column::left(4), Column::right(5)];
+/// let mut af = ArrForm::<32>::new();
+/// let mut table = af.table(&columns, " ");
+/// table.row().cell(format_args!("verylongname")).cell(format_args!("{}", 1));
+/// table.row().cell(format_args!("ok")).cell(format_args!("{}", 2));
+/// table.finish().unwrap();
+/// assert_eq!("very     1\r\nok       2\r\n", af.as_str());
+/// ```
+pub struct TableForm<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+    columns: &'a [Column],
+    separator: &'a str,
+    cell: Option<usize>,
+    result: fmt::Result,
+}
+
+impl<S: Storage, P: OverflowPolicy> TableForm<'_, S, P> {
+    /// Start a new row, the previous row is terminated
+    pub fn row(&mut self) -> &mut Self {
+        self.end_row();
+        self.cell = Some(0);
+        self
+    }
+
+    /// Format numbers and strings into the next cell of the row
+    pub fn cell(&mut self, args: fmt::Arguments) -> &mut Self {
+        let index = self.cell.unwrap_or(0);
+        self.cell = Some(index + 1);
+        if self.result.is_err() {
+            return self;
+        }
+        let Some(column) = self.columns.get(index) else {
+            self.result = Err(fmt::Error);
+            return self;
+        };
+        if index > 0 {
+            self.result = self.buffer.write_text(self.separator);
+        }
+        if self.result.is_ok() {
+            // Clipped text does not fail the table, only a full buffer does
+            let written = if column.cells {
+                self.buffer.put_field_cells(column.width, column.align, args)
+            } else {
+                self.buffer.put_field(column.width, column.align, b' ', args)
+            };
+            self.result = written.map(|_| ());
+        }
+        self
+    }
+
+    /// Terminate the last row and get the result of all calls
+    pub fn finish(&mut self) -> fmt::Result {
+        self.end_row();
+        self.result
+    }
+
+    fn end_row(&mut self) {
+        if self.cell.take().is_some() && self.result.is_ok() {
+            self.result = self.buffer.write_ascii(b"\r\n");
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Writing formatted text into a buffer and a second sink at once
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Writes every formatted fragment into a buffer and into a second [fmt::Write] sink
+///
+/// The text is formatted only once. Each fragment is written to the sink first, so the sink 
+/// receives it even if the buffer overflows.
+/// ```
+/// use core::fmt::Write;
+/// use arrform::ArrForm;
+///
+/// let mut lcd = ArrForm::<8>::new();
+/// let mut log = ArrForm::<32>::new();
+/// write!(lcd.tee(&mut log), "T={:.1}", 21.56).unwrap();
+/// assert_eq!("T=21.6", lcd.as_str());
+/// assert_eq!("T=21.6", log.as_str());
+/// ```
+pub struct Tee<'a, S: Storage, P: OverflowPolicy, W: fmt::Write> {
+    buffer: &'a mut FormBuffer<S, P>,
+    sink: &'a mut W,
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Get a writer, which appends to this buffer and to `sink` at the same time
+    pub fn tee<'a, W: fmt::Write>(&'a mut self, sink: &'a mut W) -> Tee<'a, S, P, W> {
+        Tee { buffer: self, sink }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy, W: fmt::Write> fmt::Write for Tee<'_, S, P, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let sink_result = self.sink.write_str(s);
+        self.buffer.write_text(s).and(sink_result)
+    }
+}
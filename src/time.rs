@@ -0,0 +1,100 @@
+//! ISO 8601 timestamps
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Calendar date and time of day in UTC
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    /// Year, like 2024
+    pub year: u32,
+    /// Month from 1 to 12
+    pub month: u8,
+    /// Day of the month from 1 to 31
+    pub day: u8,
+    /// Hour from 0 to 23
+    pub hour: u8,
+    /// Minute from 0 to 59
+    pub minute: u8,
+    /// Second from 0 to 59
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Convert seconds since 1970-01-01T00:00:00Z into date and time
+    ///
+    /// Leap seconds are not counted, like in Unix time.
+    /// ```
+    /// use arrform::DateTime;
+    ///
+    /// let dt = DateTime::from_unix(1_715_953_512);
+    /// assert_eq!((2024, 5, 17), (dt.year, dt.month, dt.day));
+    /// assert_eq!((13, 45, 12), (dt.hour, dt.minute, dt.second));
+    /// ```
+    pub fn from_unix(unix_secs: u64) -> Self {
+        let secs = unix_secs % 86_400;
+
+        // Days to civil date, shifted to years starting in March
+        let z = unix_secs / 86_400 + 719_468;
+        let era = z / 146_097;
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = yoe + era * 400 + (month <= 2) as u64;
+
+        DateTime {
+            year: u32::try_from(year).unwrap_or(u32::MAX),
+            month,
+            day,
+            hour: (secs / 3_600) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+        }
+    }
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append a Unix time as ISO 8601 timestamp in UTC
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_timestamp(1_715_953_512).unwrap();
+    /// assert_eq!("2024-05-17T13:45:12Z", af.as_str());
+    ///
+    /// af.clear();
+    /// af.write_timestamp(951_782_400).unwrap();
+    /// assert_eq!("2000-02-29T00:00:00Z", af.as_str());
+    /// ```
+    pub fn write_timestamp(&mut self, unix_secs: u64) -> fmt::Result {
+        self.write_datetime(&DateTime::from_unix(unix_secs))
+    }
+
+    /// Append date and time as ISO 8601 timestamp in UTC
+    /// ```
+    /// use arrform::{ArrForm, DateTime};
+    ///
+    /// let dt = DateTime { year: 2024, month: 12, day: 1, hour: 8, minute: 5, second: 0 };
+    /// let mut af = ArrForm::<32>::new();
+    /// af.write_datetime(&dt).unwrap();
+    /// assert_eq!("2024-12-01T08:05:00Z", af.as_str());
+    /// ```
+    pub fn write_datetime(&mut self, dt: &DateTime) -> fmt::Result {
+        self.write_zero_padded(dt.year, 4)?;
+        self.write_ascii(b"-")?;
+        self.write_zero_padded(dt.month as u32, 2)?;
+        self.write_ascii(b"-")?;
+        self.write_zero_padded(dt.day as u32, 2)?;
+        self.write_ascii(b"T")?;
+        self.write_zero_padded(dt.hour as u32, 2)?;
+        self.write_ascii(b":")?;
+        self.write_zero_padded(dt.minute as u32, 2)?;
+        self.write_ascii(b":")?;
+        self.write_zero_padded(dt.second as u32, 2)?;
+        self.write_ascii(b"Z")
+    }
+}
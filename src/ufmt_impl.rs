@@ -0,0 +1,88 @@
+//! Support for the `ufmt` formatting crate
+
+use core::fmt;
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+/// Allows `ufmt::uwrite!` to format into the buffer
+/// ```
+/// use arrform::ArrForm;
+/// use ufmt::uwrite;
+///
+/// let mut af = ArrForm::<16>::new();
+/// uwrite!(af, "int {}", 42).unwrap();
+/// assert_eq!("int 42", af.as_str());
+/// ```
+impl<S: Storage, P: OverflowPolicy> ufmt::uWrite for FormBuffer<S, P> {
+    type Error = fmt::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.write_text(s)
+    }
+}
+
+/// Allows embedding the text of a buffer in a larger `ufmt::uwrite!` call
+/// ```
+/// use arrform::{arrform_u, ArrForm};
+/// use ufmt::uwrite;
+///
+/// let value = arrform_u!(8, "{}", 4711);
+/// let mut af = ArrForm::<32>::new();
+/// uwrite!(af, "value {}, {:?}", value, arrform_u!(8, "a\tb")).unwrap();
+/// assert_eq!("value 4711, \"a\\tb\"", af.as_str());
+/// ```
+impl<S: Storage, P: OverflowPolicy> ufmt::uDisplay for FormBuffer<S, P> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Writes the text of a buffer as quoted string, like the `Debug` implementation
+impl<S: Storage, P: OverflowPolicy> ufmt::uDebug for FormBuffer<S, P> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        // ufmt has no debug output for str, so the escapes of `core::fmt` are written by hand
+        let s = self.as_str();
+        let mut from = 0;
+        f.write_char('"')?;
+        for (i, c) in s.char_indices() {
+            let escaped = c.escape_debug();
+            if escaped.len() != 1 {
+                f.write_str(s.get(from..i).unwrap_or_default())?;
+                for e in escaped {
+                    f.write_char(e)?;
+                }
+                from = i + c.len_utf8();
+            }
+        }
+        f.write_str(s.get(from..).unwrap_or_default())?;
+        f.write_char('"')
+    }
+}
+
+/// A macro like [arrform!](crate::arrform!), which formats with `ufmt` instead of `core::fmt`
+///
+/// `ufmt` generates much less program code. The format syntax is the one of `ufmt::uwrite!`, 
+/// so the calling crate has to depend on `ufmt` as well. The macro panics if the buffer is 
+/// chosen too small.
+/// ```
+/// use arrform::arrform_u;
+///
+/// let af = arrform_u!(16, "int {}, {}", 4711, "ok");
+/// assert_eq!("int 4711, ok", af.as_str());
+/// ```
+#[macro_export]
+macro_rules! arrform_u {
+    ($size:expr, $($arg:tt)*) => {{
+        let mut af = $crate::ArrForm::<{ $size }>::new();
+
+        // Panic on buffer overflow
+        ::ufmt::uwrite!(af, $($arg)*).expect("Buffer overflow");
+        af
+    }}
+}
@@ -0,0 +1,65 @@
+//! Percent encoding for URLs and MQTT topics
+
+use core::fmt;
+
+use crate::{radix::digit, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Append `s` with all but the unreserved characters of RFC 3986 percent encoded
+    ///
+    /// Letters, digits and `-._~` are copied, every other byte is written as `%XX`. An escape
+    /// sequence is never cut on overflow.
+    /// ```
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<64>::new();
+    /// af.push_str("/log?msg=").unwrap();
+    /// af.write_urlencoded("T=21.5 °C&ok").unwrap();
+    /// assert_eq!("/log?msg=T%3D21.5%20%C2%B0C%26ok", af.as_str());
+    /// ```
+    pub fn write_urlencoded(&mut self, s: &str) -> fmt::Result {
+        // Copy runs of unreserved characters at once
+        let mut start = 0;
+        for (i, &b) in s.as_bytes().iter().enumerate() {
+            if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                continue;
+            }
+            self.write_ascii(s.as_bytes().get(start..i).unwrap_or_default())?;
+            let escape = [b'%', digit(u32::from(b >> 4)), digit(u32::from(b & 0xf))];
+            let fit = if self.limit - self.used >= escape.len() { escape.len() } else { 0 };
+            self.write_prefix(&escape, fit)?;
+            if fit == 0 {
+                return Ok(());
+            }
+            start = i + 1;
+        }
+        self.write_ascii(s.as_bytes().get(start..).unwrap_or_default())
+    }
+
+    /// Get a writer, which appends formatted text percent encoded to this buffer
+    /// ```
+    /// use core::fmt::Write;
+    /// use arrform::ArrForm;
+    ///
+    /// let mut af = ArrForm::<64>::new();
+    /// af.push_str("sensors/").unwrap();
+    /// write!(af.urlencoded(), "{}/{}", "room 1", 4).unwrap();
+    /// assert_eq!("sensors/room%201%2F4", af.as_str());
+    /// ```
+    pub fn urlencoded(&mut self) -> UrlEncoder<'_, S, P> {
+        UrlEncoder { buffer: self }
+    }
+}
+
+/// Writer, which appends percent encoded text to a buffer
+///
+/// It is created by [ArrForm::urlencoded](crate::FormBuffer::urlencoded).
+pub struct UrlEncoder<'a, S: Storage, P: OverflowPolicy> {
+    buffer: &'a mut FormBuffer<S, P>,
+}
+
+impl<S: Storage, P: OverflowPolicy> fmt::Write for UrlEncoder<'_, S, P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buffer.write_urlencoded(s)
+    }
+}
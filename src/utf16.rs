@@ -0,0 +1,32 @@
+//! UTF-16LE output for USB string descriptors
+
+use crate::{ArrFormError, FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Convert the content to UTF-16LE in `out` and get the number of bytes written
+    ///
+    /// Characters outside the basic multilingual plane become surrogate pairs. If `out` is too
+    /// small, nothing is written and the needed size is returned in the error.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let serial = arrform!(16, "SN{:04X}", 0x1a2b);
+    /// let mut descriptor = [0u8; 32];
+    /// let len = serial.encode_utf16_into(&mut descriptor[2..]).unwrap();
+    /// descriptor[0] = 2 + len as u8;
+    /// descriptor[1] = 3;
+    /// assert_eq!(b"\x0e\x03S\0N\x001\0A\x002\0B\0", &descriptor[..14]);
+    ///
+    /// assert!(serial.encode_utf16_into(&mut [0u8; 8]).is_err());
+    /// ```
+    pub fn encode_utf16_into(&self, out: &mut [u8]) -> Result<usize, ArrFormError> {
+        let needed = self.as_str().encode_utf16().count() * 2;
+        if needed > out.len() {
+            return Err(ArrFormError::Overflow { capacity: out.len(), needed_at_least: needed });
+        }
+        for (unit, bytes) in self.as_str().encode_utf16().zip(out.chunks_exact_mut(2)) {
+            bytes.copy_from_slice(&unit.to_le_bytes());
+        }
+        Ok(needed)
+    }
+}
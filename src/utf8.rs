@@ -0,0 +1,60 @@
+//! Conversion of bytes, which are known to be UTF-8 text
+
+/// Get bytes, which only contain complete characters, as str without validation
+#[cfg(not(feature = "safe"))]
+pub(crate) const fn from_utf8_trusted(bytes: &[u8]) -> &str {
+    // Safety: The callers only pass complete characters
+    unsafe { core::str::from_utf8_unchecked(bytes) }
+}
+
+/// Get bytes, which only contain complete characters, as str
+#[cfg(feature = "safe")]
+pub(crate) const fn from_utf8_trusted(bytes: &[u8]) -> &str {
+    valid_prefix(bytes)
+}
+
+/// Get bytes, which only contain complete characters, as mutable str without validation
+#[cfg(not(feature = "safe"))]
+pub(crate) fn from_utf8_trusted_mut(bytes: &mut [u8]) -> &mut str {
+    // Safety: The callers only pass complete characters
+    unsafe { core::str::from_utf8_unchecked_mut(bytes) }
+}
+
+/// Get bytes, which only contain complete characters, as mutable str
+#[cfg(feature = "safe")]
+pub(crate) fn from_utf8_trusted_mut(bytes: &mut [u8]) -> &mut str {
+    valid_prefix_mut(bytes)
+}
+
+/// Get the valid UTF-8 text in front of bytes of any content
+pub(crate) const fn valid_prefix(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => match core::str::from_utf8(bytes.split_at(e.valid_up_to()).0) {
+            Ok(s) => s,
+            Err(_) => "",
+        },
+    }
+}
+
+/// Get the valid UTF-8 text in front of bytes of any content as mutable str
+pub(crate) fn valid_prefix_mut(bytes: &mut [u8]) -> &mut str {
+    let valid = core::str::from_utf8(bytes).map_or_else(|e| e.valid_up_to(), str::len);
+    core::str::from_utf8_mut(bytes.get_mut(..valid).unwrap_or_default()).unwrap_or_default()
+}
+
+/// Get the text in front of `bytes`, which fits into `room` bytes
+///
+/// A character cut by `room` is left out. An error is returned if the bytes start with invalid
+/// UTF-8 or with a character, which is incomplete although all bytes fit.
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub(crate) fn text_prefix(bytes: &[u8], room: usize) -> Result<&str, core::str::Utf8Error> {
+    let head = bytes.get(..room).unwrap_or(bytes);
+    match core::str::from_utf8(head) {
+        Ok(s) => Ok(s),
+        Err(e) if e.valid_up_to() > 0 || (e.error_len().is_none() && head.len() < bytes.len()) => {
+            Ok(valid_prefix(head))
+        }
+        Err(e) => Err(e),
+    }
+}
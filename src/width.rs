@@ -0,0 +1,180 @@
+//! Alignment by the display width of characters on terminals and monospace displays
+
+use core::fmt;
+
+use crate::{Align, ArrFormRef, FormBuffer, OverflowPolicy, SaturatePolicy, Storage};
+
+/// Ranges of combining marks and other characters, which take no cell
+const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x0300, 0x036f),
+    (0x0483, 0x0489),
+    (0x0591, 0x05bd),
+    (0x0610, 0x061a),
+    (0x064b, 0x065f),
+    (0x0e31, 0x0e31),
+    (0x0e34, 0x0e3a),
+    (0x0e47, 0x0e4e),
+    (0x1ab0, 0x1aff),
+    (0x1dc0, 0x1dff),
+    (0x200b, 0x200f),
+    (0x2028, 0x202e),
+    (0x2060, 0x2064),
+    (0x20d0, 0x20ff),
+    (0x302a, 0x302f),
+    (0x3099, 0x309a),
+    (0xfe00, 0xfe0f),
+    (0xfe20, 0xfe2f),
+    (0xfeff, 0xfeff),
+    (0xe0100, 0xe01ef),
+];
+
+/// Ranges of wide East Asian characters and emoji, which take two cells
+const DOUBLE_WIDTH: &[(u32, u32)] = &[
+    (0x1100, 0x115f),
+    (0x2e80, 0x303e),
+    (0x3041, 0x33ff),
+    (0x3400, 0x4dbf),
+    (0x4e00, 0x9fff),
+    (0xa000, 0xa4cf),
+    (0xa960, 0xa97f),
+    (0xac00, 0xd7a3),
+    (0xf900, 0xfaff),
+    (0xfe10, 0xfe19),
+    (0xfe30, 0xfe6f),
+    (0xff00, 0xff60),
+    (0xffe0, 0xffe6),
+    (0x1f300, 0x1f64f),
+    (0x1f900, 0x1f9ff),
+    (0x20000, 0x2fffd),
+    (0x30000, 0x3fffd),
+];
+
+/// Get the number of cells a character takes on a terminal, like `wcwidth`
+///
+/// Control characters and combining marks take no cell, wide East Asian characters and emoji
+/// take two.
+pub fn char_width(c: char) -> usize {
+    let contains = |ranges: &[(u32, u32)]| {
+        ranges.iter().any(|&(first, last)| (first..=last).contains(&u32::from(c)))
+    };
+    if c.is_control() || contains(ZERO_WIDTH) {
+        0
+    } else if contains(DOUBLE_WIDTH) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Get the number of cells a string takes on a terminal, see [char_width]
+/// ```
+/// use arrform::display_width;
+///
+/// assert_eq!(6, display_width("Zürich"));
+/// assert_eq!(6, display_width("東京都"));
+/// assert_eq!(4, display_width("Jose\u{301}"));
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Format numbers and strings into a field of exactly `width` display cells
+    ///
+    /// Works like [ArrForm::write_field] with spaces as fill, but counts the cells of a
+    /// terminal or monospace display instead of bytes, so names with wide or combining
+    /// characters stay aligned. Text wider than the field is clipped after the last character,
+    /// which fits completely, and an overflow is reported.
+    /// ```
+    /// use arrform::{Align, ArrForm};
+    ///
+    /// let mut af = ArrForm::<64>::new();
+    /// for name in ["Anna", "山田太郎", "Jose\u{301}"] {
+    ///     af.write_field_cells(10, Align::Left, format_args!("{}", name)).unwrap();
+    ///     af.push_str("|\n").unwrap();
+    /// }
+    /// assert_eq!("Anna      |\n山田太郎  |\nJose\u{301}      |\n", af.as_str());
+    ///
+    /// af.clear();
+    /// assert!(af.write_field_cells(5, Align::Right, format_args!("{}", "山田太郎")).is_err());
+    /// assert_eq!(" 山田", af.as_str());
+    ///
+    /// let mut af = ArrForm::<6>::new();
+    /// assert!(af.write_field_cells(8, Align::Right, format_args!("ab")).is_err());
+    /// assert_eq!("    ab", af.as_str());
+    /// ```
+    ///
+    /// [ArrForm::write_field]: crate::FormBuffer::write_field
+    pub fn write_field_cells(
+        &mut self,
+        width: usize,
+        align: Align,
+        args: fmt::Arguments,
+    ) -> fmt::Result {
+        let clipped = self.put_field_cells(width, align, args)?;
+        if clipped > 0 {
+            self.dropped += clipped;
+            return self.on_overflow();
+        }
+        Ok(())
+    }
+
+    /// Write a field like [ArrForm::write_field_cells] and get the number of bytes clipped
+    ///
+    /// Clipping is not an overflow here, only text or padding reaching beyond the buffer is.
+    ///
+    /// [ArrForm::write_field_cells]: crate::FormBuffer::write_field_cells
+    pub(crate) fn put_field_cells(
+        &mut self,
+        width: usize,
+        align: Align,
+        args: fmt::Arguments,
+    ) -> Result<usize, fmt::Error> {
+        let start = self.used;
+
+        // Format into the rest of the buffer, the end only matters if the text is not clipped
+        let area = self.buffer.as_mut_slice().get_mut(start..self.limit).ok_or(fmt::Error)?;
+        let mut form = ArrFormRef::<SaturatePolicy>::from_storage(area);
+        let result = form.append(args);
+        let text = form.as_str();
+        let mut cells = 0;
+        let mut fit = text.len();
+        for (i, c) in text.char_indices() {
+            let w = char_width(c);
+            if cells + w > width {
+                fit = i;
+                break;
+            }
+            cells += w;
+        }
+        let (len, lost) = (text.len(), form.dropped);
+
+        self.used = start + fit;
+        let clipped = if fit < len {
+            len - fit + lost
+        } else {
+            if lost > 0 {
+                self.dropped += lost;
+                self.on_overflow()?;
+            }
+            0
+        };
+
+        let padding = width.saturating_sub(cells);
+        if align == Align::Right {
+            // Shift the text once by the padding that fits and fill the gap
+            let shift = padding.min(self.limit - self.used);
+            let buffer = self.buffer.as_mut_slice();
+            buffer.copy_within(start..self.used, start + shift);
+            buffer.get_mut(start..start + shift).ok_or(fmt::Error)?.fill(b' ');
+            self.used += shift;
+            if shift < padding {
+                self.dropped += padding - shift;
+                self.on_overflow()?;
+            }
+        } else {
+            self.write_spaces(padding)?;
+        }
+        result.map(|()| clipped)
+    }
+}
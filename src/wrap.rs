@@ -0,0 +1,92 @@
+//! Word wrapping for displays
+
+use crate::{FormBuffer, OverflowPolicy, Storage};
+
+impl<S: Storage, P: OverflowPolicy> FormBuffer<S, P> {
+    /// Iterate over the content wrapped into lines of at most `width` characters
+    ///
+    /// Lines are broken at spaces when possible, longer words are split. Line breaks in the
+    /// text are kept.
+    /// ```
+    /// use arrform::arrform;
+    ///
+    /// let af = arrform!(64, "Battery low: {}%, please connect the charger", 7);
+    /// let mut lines = af.wrap(16);
+    /// assert_eq!(Some("Battery low: 7%,"), lines.next());
+    /// assert_eq!(Some("please connect"), lines.next());
+    /// assert_eq!(Some("the charger"), lines.next());
+    /// assert_eq!(None, lines.next());
+    /// ```
+    pub fn wrap(&self, width: usize) -> WordWrap<'_> {
+        WordWrap { rest: self.as_str(), width: width.max(1) }
+    }
+}
+
+/// Iterator over wrapped lines, see [ArrForm::wrap](crate::FormBuffer::wrap)
+pub struct WordWrap<'a> {
+    rest: &'a str,
+    width: usize,
+}
+
+impl<'a> WordWrap<'a> {
+    /// Creates an iterator over `text` wrapped into lines of at most `width` characters
+    /// ```
+    /// use arrform::WordWrap;
+    ///
+    /// let lines: Vec<&str> = WordWrap::new("abcdefgh ij\nk", 4).collect();
+    /// assert_eq!(vec!["abcd", "efgh", "ij", "k"], lines);
+    ///
+    /// let lines: Vec<&str> = WordWrap::new("grüße an alle", 5).collect();
+    /// assert_eq!(vec!["grüße", "an", "alle"], lines);
+    /// let lines: Vec<&str> = WordWrap::new("abcdé", 4).collect();
+    /// assert_eq!(vec!["abcd", "é"], lines);
+    /// ```
+    pub fn new(text: &'a str, width: usize) -> Self {
+        WordWrap { rest: text, width: width.max(1) }
+    }
+}
+
+impl<'a> Iterator for WordWrap<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        // Byte offset after `width` characters, stopping at a line break
+        let mut end = self.rest.len();
+        for (count, (i, c)) in self.rest.char_indices().enumerate() {
+            if c == '\n' {
+                let line = &self.rest[..i];
+                self.rest = &self.rest[i + 1..];
+                return Some(line);
+            }
+            if count == self.width {
+                end = i;
+                break;
+            }
+        }
+        if end == self.rest.len() {
+            let line = self.rest;
+            self.rest = "";
+            return Some(line);
+        }
+
+        // Break at the last space, which may directly follow the line
+        let space = if self.rest[end..].starts_with(' ') {
+            Some(end)
+        } else {
+            self.rest[..end].rfind(' ')
+        };
+        let (line, rest) = match space {
+            Some(space) if self.rest[..space].trim_end_matches(' ').is_empty() => {
+                self.rest.split_at(end)
+            }
+            Some(space) => (&self.rest[..space], &self.rest[space..]),
+            None => self.rest.split_at(end),
+        };
+        self.rest = rest.trim_start_matches(' ');
+        Some(line.trim_end_matches(' '))
+    }
+}